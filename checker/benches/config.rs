@@ -0,0 +1,208 @@
+//! Benchmarks CONFIG.toml checking and weightclass lookups.
+//!
+//! Criterion only measures time, so the peak heap usage of checking is
+//! measured separately by a counting allocator and printed.
+
+use checker::checklib::config::WeightClassConfig;
+use checker::{Config, ConfigRegistry};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use opltypes::{Date, Equipment, Federation, Sex};
+use strum::IntoEnumIterator;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Heap bytes currently allocated.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// The most heap bytes allocated at once since the last reset.
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, tracking current and peak usage.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, returning the most heap bytes it had allocated at once.
+fn peak_heap_usage<T>(f: impl FnOnce() -> T) -> usize {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    black_box(f());
+    PEAK.load(Ordering::Relaxed) - base
+}
+
+/// Builds a CONFIG.toml with weightclasses changing every `step` years since 1970,
+/// plus division-specific groups for some divisions.
+fn make_toml(step: usize) -> String {
+    let mut toml = String::from("[divisions]\n");
+    let divisions = [
+        "Open",
+        "Juniors",
+        "Sub-Juniors",
+        "Teen 1",
+        "Teen 2",
+        "Teen 3",
+        "Masters 1",
+        "Masters 2",
+        "Masters 3",
+        "Masters 4",
+        "Guest",
+        "Novice",
+    ];
+    for (i, name) in divisions.iter().enumerate() {
+        writeln!(toml, "d{i} = {{ name = \"{name}\", min = 0, max = 999 }}").unwrap();
+    }
+
+    toml += "[weightclasses]\n";
+    for year in (1970..2030).step_by(step) {
+        for sex in ["M", "F"] {
+            let range = format!("[\"{year}-01-01\", \"{}-12-31\"]", year + step - 1);
+            writeln!(toml, "[weightclasses.default_{sex}_{year}]").unwrap();
+            writeln!(toml, "classes = [\"60\", \"75\", \"90\", \"90+\"]").unwrap();
+            writeln!(toml, "date_range = {range}\nsex = \"{sex}\"").unwrap();
+            writeln!(toml, "[weightclasses.youth_{sex}_{year}]").unwrap();
+            writeln!(toml, "classes = [\"50\", \"60\", \"60+\"]").unwrap();
+            writeln!(toml, "date_range = {range}\nsex = \"{sex}\"").unwrap();
+            writeln!(
+                toml,
+                "divisions = [\"Sub-Juniors\", \"Teen 1\", \"Teen 2\", \"Teen 3\", \"Juniors\"]"
+            )
+            .unwrap();
+        }
+    }
+    toml += "[exemptions]\n";
+    for meet in 0..(6000 / step) {
+        writeln!(toml, "\"{meet:04}\" = [\"ExemptAge\", \"ExemptDivision\"]").unwrap();
+    }
+    toml
+}
+
+/// Checks a CONFIG.toml written to a temporary file.
+fn check_toml(toml: &str) -> Config {
+    let path = std::env::temp_dir().join("checker_bench_config.toml");
+    std::fs::write(&path, toml).unwrap();
+    let config = checker::check_config(path.clone(), false)
+        .unwrap()
+        .config
+        .unwrap();
+    std::fs::remove_file(path).unwrap();
+    config
+}
+
+/// The lookup performed by scanning every weightclass group, for comparison.
+fn linear_lookup<'a>(
+    config: &'a Config,
+    sex: Sex,
+    date: Date,
+    division: &str,
+) -> Option<&'a WeightClassConfig> {
+    let mut candidates = config
+        .weightclasses()
+        .iter()
+        .filter(|wc| wc.sex == sex && wc.contains_date(date));
+    let lists_division = |wc: &&WeightClassConfig| match &wc.divisions {
+        Some(divs) => divs.iter().any(|&i| config.divisions()[i].name == division),
+        None => false,
+    };
+    candidates
+        .clone()
+        .find(lists_division)
+        .or_else(|| candidates.find(|wc| wc.divisions.is_none()))
+}
+
+pub fn config_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("check_config");
+    for step in [2, 1] {
+        let toml = make_toml(step);
+        let name = format!("{}KiB", toml.len() / 1024);
+        group.bench_function(&name, |b| {
+            b.iter(|| black_box(check_toml(&toml)));
+        });
+
+        let peak = peak_heap_usage(|| check_toml(&toml));
+        println!("check_config/{name}: peak heap usage {}KiB", peak / 1024);
+    }
+    group.finish();
+
+    let config = check_toml(&make_toml(2));
+    let queries: Vec<(Sex, Date, &str)> = (0..1000)
+        .map(|i| {
+            let sex = if i % 2 == 0 { Sex::M } else { Sex::F };
+            let date = Date::from_parts(1970 + (i % 59), 1 + (i % 12), 1 + (i % 28));
+            let division = ["Open", "Juniors", "Masters 2", "Teen 3"][i as usize % 4];
+            (sex, date, division)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("weightclasses");
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            for &(sex, date, division) in &queries {
+                black_box(linear_lookup(&config, sex, date, division));
+            }
+        });
+    });
+    group.bench_function("indexed", |b| {
+        b.iter(|| {
+            for &(sex, date, division) in &queries {
+                black_box(config.effective_weightclasses_for_entry(
+                    sex,
+                    Equipment::Raw,
+                    date,
+                    Some(division),
+                ));
+            }
+        });
+    });
+    group.finish();
+}
+
+/// Compares parsing every CONFIG.toml in a meet-data tree to loading a cache.
+pub fn registry_benchmarks(c: &mut Criterion) {
+    let root = std::env::temp_dir().join("checker_bench_registry");
+    let _ = std::fs::remove_dir_all(&root);
+    let toml = make_toml(4);
+    for federation in Federation::iter().take(100) {
+        let dir = root.join(federation.to_string().to_lowercase());
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("CONFIG.toml"), &toml).unwrap();
+    }
+    let cache = root.join("registry.bin");
+    ConfigRegistry::from_meet_data(&root)
+        .unwrap()
+        .save_cache(&cache)
+        .unwrap();
+
+    let mut group = c.benchmark_group("registry");
+    group.sample_size(10);
+    group.bench_function("parse", |b| {
+        b.iter(|| black_box(ConfigRegistry::from_meet_data(&root).unwrap()));
+    });
+    group.bench_function("load_cache", |b| {
+        b.iter(|| black_box(ConfigRegistry::load_cache(&cache).unwrap()));
+    });
+    group.finish();
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+criterion_group!(benches, config_benchmarks, registry_benchmarks);
+criterion_main!(benches);
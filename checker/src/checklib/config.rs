@@ -1,31 +1,120 @@
 //! Checks for CONFIG.toml files.
 
 use opltypes::*;
+use serde::{Deserialize as _, Deserializer, Serializer};
+use serde_derive::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use toml::{self, Value};
+use unicode_normalization::UnicodeNormalization;
 
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::error::Error;
-use std::fs::File;
-use std::path::PathBuf;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use std::io::Read;
-
-use crate::Report;
+use crate::{Message, Report};
 
 pub struct CheckResult {
     pub report: Report,
     pub config: Option<Config>,
+    /// Time spent reading, parsing, and validating the file, if requested.
+    pub elapsed: Option<Duration>,
+    /// The federation whose folder contains the file, if known.
+    pub federation: Option<Federation>,
 }
 
-#[derive(Debug)]
+/// A validated CONFIG.toml.
+///
+/// This can be serialized and reloaded later without revalidating the file.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ConfigParts")]
 pub struct Config {
     pub options: Option<OptionConfig>,
-    pub divisions: Vec<DivisionConfig>,
-    pub weightclasses: Vec<WeightClassConfig>,
+
+    /// Private so that `division_index` can't go stale: see [`Config::divisions()`].
+    divisions: Vec<DivisionConfig>,
+
+    /// Private so that `weightclass_index` can't go stale: see [`Config::weightclasses()`].
+    weightclasses: Vec<WeightClassConfig>,
+
     pub exemptions: Vec<ExemptionConfig>,
     pub rulesets: Vec<RuleSetConfig>,
+    pub scoring: Option<ScoringConfig>,
+
+    /// Documentation from `#:` comments in the file, one entry per comment block.
+    pub doc_comments: Vec<String>,
+
+    /// Alternate division names from the "aliases" table, mapped to the
+    /// name of the division they stand for.
+    pub aliases: HashMap<String, String>,
+
+    /// The federation whose weightclasses this one inherits, from `metadata.parent_federation`.
+    pub parent_federation: Option<Federation>,
+
+    /// When fourth attempts are allowed, from `metadata.allow_fourth_attempts`.
+    pub fourth_attempts: FourthAttemptPolicy,
+
+    /// Index for quickly finding the weightclass groups that apply to an entry.
+    ///
+    /// This is built by `Config::new()`.
+    #[serde(skip)]
+    weightclass_index: WeightClassIndex,
+
+    /// Maps each division name to its index in `divisions`.
+    ///
+    /// This is built by `Config::new()`.
+    #[serde(skip)]
+    division_index: HashMap<String, usize>,
 }
 
-#[derive(Debug, Default)]
+/// The serialized form of a Config, from which the lookup structures are rebuilt.
+#[derive(Deserialize)]
+struct ConfigParts {
+    options: Option<OptionConfig>,
+    divisions: Vec<DivisionConfig>,
+    weightclasses: Vec<WeightClassConfig>,
+    exemptions: Vec<ExemptionConfig>,
+    rulesets: Vec<RuleSetConfig>,
+    scoring: Option<ScoringConfig>,
+    #[serde(default)]
+    doc_comments: Vec<String>,
+    #[serde(default)]
+    aliases: HashMap<String, String>,
+    #[serde(default)]
+    parent_federation: Option<Federation>,
+    #[serde(default)]
+    fourth_attempts: FourthAttemptPolicy,
+}
+
+impl From<ConfigParts> for Config {
+    fn from(p: ConfigParts) -> Config {
+        let config = Config::new(
+            p.options,
+            p.divisions,
+            p.weightclasses,
+            p.exemptions,
+            p.rulesets,
+            p.scoring,
+        );
+        Config {
+            doc_comments: p.doc_comments,
+            aliases: p.aliases,
+            parent_federation: p.parent_federation,
+            fourth_attempts: p.fourth_attempts,
+            ..config
+        }
+    }
+}
+
+/// Maps (Sex, division index) to the date ranges of the Config's `weightclasses`,
+/// as `(start, end, index)` triples sorted by `start`.
+///
+/// A group with several date ranges has one triple per range.
+/// Groups without a division restriction are stored under a division index of `None`.
+type WeightClassIndex = HashMap<(Sex, Option<usize>), Vec<(Date, Date, usize)>>;
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct OptionConfig {
     /// Option that specifies the config only affects meets after a certain
     /// Date, allowing for partial federation configuration.
@@ -34,9 +123,12 @@ pub struct OptionConfig {
     /// If set to true, pending disambiguations governed by this configuration
     /// become errors.
     pub require_manual_disambiguation: bool,
+
+    /// The federation name that meets in this folder are expected to use.
+    pub federation: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct DivisionConfig {
     /// The name of the division.
     pub name: String,
@@ -52,10 +144,62 @@ pub struct DivisionConfig {
     /// Tested column.
     pub tested: Option<bool>,
     /// Specifies a Place that this division must have. Used for Guests.
+    #[serde(serialize_with = "serialize_place")]
     pub place: Option<Place>,
+    /// Optional flag for divisions that only exist for records purposes,
+    /// such as single-lift records, and should not feed normal placings.
+    pub records_only: Option<bool>,
+    /// Marks the catch-all division for lifters that match no other division.
+    pub fallback: bool,
+    /// How a lifter's age is compared against `min` and `max`.
+    #[serde(default)]
+    pub age_mode: AgeMode,
+    /// Position in the federation's display order, like Open before Juniors.
+    #[serde(default)]
+    pub sort_order: Option<u32>,
+    /// Optional restriction to athletes of a competition level, like National.
+    #[serde(default)]
+    pub level: Option<CompetitionLevel>,
+    /// Optional cutoff admitting only lifters born after this date.
+    #[serde(default)]
+    pub born_after: Option<Date>,
+    /// Optional cutoff admitting only lifters born before this date.
+    #[serde(default)]
+    pub born_before: Option<Date>,
+}
+
+/// How a division determines a lifter's age.
+#[derive(Copy, Clone, Debug, Default, Deserialize, EnumString, PartialEq, Eq, Serialize)]
+pub enum AgeMode {
+    /// The lifter's age on the day of the meet, as in most federations.
+    #[default]
+    #[strum(serialize = "age")]
+    Age,
+
+    /// The lifter's age at the end of the year of the meet, which is the
+    /// meet's year minus the birth year.
+    ///
+    /// Some youth federations use birth-year cohorts, so that a lifter stays
+    /// in the same division all year, regardless of their birthday.
+    #[strum(serialize = "birthyear")]
+    BirthYear,
+}
+
+/// When a federation allows fourth attempts, which don't count towards the total.
+#[derive(Copy, Clone, Debug, Default, Deserialize, EnumString, PartialEq, Eq, Serialize)]
+pub enum FourthAttemptPolicy {
+    /// Fourth attempts may be taken for any reason, as assumed without the metadata.
+    #[default]
+    Always,
+
+    /// Fourth attempts may only be taken to break a world record.
+    WROnly,
+
+    /// Fourth attempts are not allowed.
+    Never,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct WeightClassConfig {
     /// The name of the TOML table member.
     ///
@@ -64,10 +208,11 @@ pub struct WeightClassConfig {
 
     /// List of weightclasses with the provided parameters.
     pub classes: Vec<WeightClassKg>,
-    /// The earliest date at which these weightclasses existed.
-    pub date_min: Date,
-    /// The last date at which these weightclasses existed.
-    pub date_max: Date,
+    /// The inclusive `(first, last)` date ranges in which these weightclasses existed.
+    ///
+    /// Ranges are sorted and never overlap. Most sets have a single range, but
+    /// a federation may bring back an older ladder, like `[[a, b], [c, d]]`.
+    pub date_ranges: Vec<(Date, Date)>,
     /// Which sex these weightclasses are for.
     pub sex: Sex,
 
@@ -75,11 +220,147 @@ pub struct WeightClassConfig {
     ///
     /// These are stored as indices into the Config's `divisions` list.
     pub divisions: Option<Vec<usize>>,
+
+    /// Specifies that these weightclasses are only for certain Equipment.
+    #[serde(default)]
+    pub equipment: Option<Vec<Equipment>>,
+
+    /// Classes in `classes` that are not yet official, like `{ kg = 90, provisional = true }`.
+    #[serde(default)]
+    pub provisional: Vec<WeightClassKg>,
+
+    /// Where the classes came from, like a rulebook edition or a URL.
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+/// Weightclass sets still in use end on or after this date, like `9999-01-01`.
+pub const WEIGHTCLASS_DATE_PRESENT: Date = Date::from_parts(9999, 01, 01);
+
+impl WeightClassConfig {
+    /// Whether these weightclasses may be used with the given Equipment.
+    pub fn admits_equipment(&self, equipment: Equipment) -> bool {
+        self.equipment
+            .as_ref()
+            .is_none_or(|e| e.contains(&equipment))
+    }
+
+    /// The number of classes in the set, including any SHW class.
+    pub fn class_count(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Whether the set ends in an unbounded SHW class, like "120+".
+    pub fn has_shw_class(&self) -> bool {
+        matches!(self.heaviest_class(), Some(WeightClassKg::Over(_)))
+    }
+
+    /// The lightest class, which is first since classes are in ascending order.
+    pub fn lightest_class(&self) -> Option<&WeightClassKg> {
+        self.classes.first()
+    }
+
+    /// The heaviest class, which is last since classes are in ascending order.
+    pub fn heaviest_class(&self) -> Option<&WeightClassKg> {
+        self.classes.last()
+    }
+
+    /// Whether any of the set's date ranges includes the date.
+    pub fn contains_date(&self, date: Date) -> bool {
+        self.date_ranges
+            .iter()
+            .any(|&(min, max)| min <= date && date <= max)
+    }
+
+    /// Whether the two sets were ever in use on the same date.
+    pub fn overlaps(&self, other: &WeightClassConfig) -> bool {
+        self.date_ranges.iter().any(|&(a_min, a_max)| {
+            other
+                .date_ranges
+                .iter()
+                .any(|&(b_min, b_max)| a_min <= b_max && b_min <= a_max)
+        })
+    }
+
+    /// The class a lifter with the given bodyweight competes in.
+    ///
+    /// That's the lightest class whose limit is at or above the bodyweight,
+    /// so a bodyweight exactly on a limit belongs to that class. Heavier lifters
+    /// fall into the open `+` class, if the set has one. Otherwise, and for
+    /// an unknown (zero) bodyweight, returns `None`.
+    pub fn class_for_bodyweight(&self, bw: WeightKg) -> Option<WeightClassKg> {
+        if bw.is_zero() {
+            return None;
+        }
+        self.classes
+            .iter()
+            .copied()
+            .find(|class| class.matches_bodyweight(bw))
+    }
+
+    /// Whether the given class was marked as provisional.
+    pub fn is_provisional(&self, class: WeightClassKg) -> bool {
+        self.provisional.contains(&class)
+    }
+
+    /// Formats the date ranges like `2012-01-01–2017-12-31`, separated by an en-dash.
+    ///
+    /// Sets that are still in use end in `present`.
+    /// Multiple ranges are separated by commas.
+    pub fn date_range_string(&self) -> String {
+        let ranges: Vec<String> = self
+            .date_ranges
+            .iter()
+            .map(|&(min, max)| {
+                if max >= WEIGHTCLASS_DATE_PRESENT {
+                    format!("{min}–present")
+                } else {
+                    format!("{min}–{max}")
+                }
+            })
+            .collect();
+        ranges.join(", ")
+    }
+}
+
+impl fmt::Display for WeightClassConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[weightclasses.{}] ({})",
+            self.name,
+            self.date_range_string()
+        )
+    }
+}
+
+/// Controls how strictly a CONFIG.toml is parsed.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Downgrades a missing `divisions`, `weightclasses`, or `exemptions` section
+    /// from an error to a warning, parsing it as empty.
+    ///
+    /// Some federations only define weightclasses, handling divisions elsewhere,
+    /// so this lets partial configs be inspected.
+    pub allow_missing_sections: bool,
+}
+
+/// Selects the optional checks run by [`Config::validate`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ValidateOptions {
+    /// Re-runs the structural checks that parsing performs, via [`Config::sanity_check`].
+    pub structural: bool,
+    /// Runs heuristic lints that produce false positives, like weightclass neighbors.
+    pub heuristics: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct RuleSetConfig {
     /// The active RuleSet for the given date range.
+    #[serde(
+        serialize_with = "serialize_ruleset",
+        deserialize_with = "deserialize_ruleset"
+    )]
     pub ruleset: RuleSet,
     /// The earliest date on which to apply this RuleSet.
     pub date_min: Date,
@@ -87,13 +368,27 @@ pub struct RuleSetConfig {
     pub date_max: Date,
 }
 
+/// The scoring formula used by the federation.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    /// The name of the formula, which must be a known `PointsSystem`.
+    pub formula: String,
+    /// Parameters for the formula, which are not yet interpreted.
+    pub params: HashMap<String, f64>,
+}
+
 /// Used to exempt a specific meet from some of the checks.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Eq)]
+#[derive(
+    Copy, Clone, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Hash, Serialize,
+)]
 pub enum Exemption {
     /// Exempts the meet from having only known divisions.
     ExemptDivision,
 
     /// Exempts the meet from requiring monotonically ascending attempts.
+    ///
+    /// The `FourthAttemptsMayLower` rule only relaxes fourth attempts, so it
+    /// never makes this exemption redundant.
     ExemptLiftOrder,
 
     /// Allows lifters of any bodyweight to compete in any weightclass.
@@ -101,9 +396,15 @@ pub enum Exemption {
 
     /// Allows a meet to contain implausibly young or old lifters.
     ExemptAge,
+
+    /// Allows a meet to omit the Event, for results that only recorded totals.
+    ExemptMissingEvent,
+
+    /// Treats a BodyweightKg of zero as missing, for data that used 0 as a sentinel.
+    ExemptBodyweightZero,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ExemptionConfig {
     /// Name of the folder containing the meet relative to the CONFIG.toml,
     /// like "9804".
@@ -112,7 +413,205 @@ pub struct ExemptionConfig {
     exemptions: Vec<Exemption>,
 }
 
+impl ExemptionConfig {
+    /// Collects the exemptions into a set, for checking many of them at once.
+    pub fn into_exemption_set(&self) -> HashSet<Exemption> {
+        self.exemptions.iter().copied().collect()
+    }
+
+    /// Whether the entry names a meet folder and exempts it from something.
+    ///
+    /// An entry failing this has no effect.
+    pub fn is_valid(&self) -> bool {
+        !self.meet_folder.trim().is_empty() && !self.exemptions.is_empty()
+    }
+
+    /// Returns a copy with repeated exemptions removed, keeping the first of each.
+    pub fn deduplicated(&self) -> ExemptionConfig {
+        let mut seen = HashSet::with_capacity(self.exemptions.len());
+        ExemptionConfig {
+            meet_folder: self.meet_folder.clone(),
+            exemptions: self
+                .exemptions
+                .iter()
+                .copied()
+                .filter(|&e| seen.insert(e))
+                .collect(),
+        }
+    }
+}
+
+/// Serializes a Place as a string, which is the form that Place deserializes from.
+fn serialize_place<S: Serializer>(place: &Option<Place>, serializer: S) -> Result<S::Ok, S::Error> {
+    match place {
+        Some(place) => serializer.serialize_some(&place.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serializes a RuleSet as its list of Rules.
+///
+/// RuleSet's own form is either a string or a number, which only self-describing
+/// formats can read back. Configs are also cached with bincode, which is not.
+fn serialize_ruleset<S: Serializer>(ruleset: &RuleSet, serializer: S) -> Result<S::Ok, S::Error> {
+    let rules: Vec<Rule> = Rule::iter().filter(|&r| ruleset.contains(r)).collect();
+    serializer.collect_seq(rules)
+}
+
+/// Deserializes a RuleSet from the list of Rules written by `serialize_ruleset()`.
+fn deserialize_ruleset<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RuleSet, D::Error> {
+    let mut ruleset = RuleSet::default();
+    for rule in Vec::<Rule>::deserialize(deserializer)? {
+        ruleset.add(rule);
+    }
+    Ok(ruleset)
+}
+
+/// A single difference between two Configs, identified by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigChange {
+    Added(String),
+    Removed(String),
+    Changed(String),
+}
+
+/// The differences between two Configs, as produced by `Config::diff()`.
+///
+/// Divisions are identified by name, weightclasses by their TOML key,
+/// and exemptions by their meet folder.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    pub divisions: Vec<ConfigChange>,
+    pub weightclasses: Vec<ConfigChange>,
+    pub exemptions: Vec<ConfigChange>,
+}
+
+impl ConfigDiff {
+    /// Whether the diff found no differences.
+    pub fn is_empty(&self) -> bool {
+        self.divisions.is_empty() && self.weightclasses.is_empty() && self.exemptions.is_empty()
+    }
+}
+
+/// Compares two lists of named items, in the order of the old list
+/// followed by any additions.
+fn diff_by_name<T>(
+    old: &[T],
+    new: &[T],
+    name: impl Fn(&T) -> &str,
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<ConfigChange> {
+    let mut changes = vec![];
+    for a in old {
+        match new.iter().find(|b| name(b) == name(a)) {
+            Some(b) if same(a, b) => (),
+            Some(_) => changes.push(ConfigChange::Changed(name(a).to_string())),
+            None => changes.push(ConfigChange::Removed(name(a).to_string())),
+        }
+    }
+    for b in new {
+        if !old.iter().any(|a| name(a) == name(b)) {
+            changes.push(ConfigChange::Added(name(b).to_string()));
+        }
+    }
+    changes
+}
+
 impl Config {
+    /// Creates a Config, building the internal lookup structures.
+    pub fn new(
+        options: Option<OptionConfig>,
+        divisions: Vec<DivisionConfig>,
+        weightclasses: Vec<WeightClassConfig>,
+        exemptions: Vec<ExemptionConfig>,
+        rulesets: Vec<RuleSetConfig>,
+        scoring: Option<ScoringConfig>,
+    ) -> Config {
+        let mut weightclass_index = WeightClassIndex::new();
+        for (i, wc) in weightclasses.iter().enumerate() {
+            let keys: Vec<Option<usize>> = match &wc.divisions {
+                Some(divs) => divs.iter().map(|&div| Some(div)).collect(),
+                None => vec![None],
+            };
+            for key in keys {
+                let ranges = weightclass_index.entry((wc.sex, key)).or_default();
+                ranges.extend(wc.date_ranges.iter().map(|&(min, max)| (min, max, i)));
+            }
+        }
+        for ranges in weightclass_index.values_mut() {
+            ranges.sort_by_key(|&(min, _, _)| min);
+        }
+
+        // Duplicate names are reported during parsing: the first one wins.
+        let mut division_index = HashMap::with_capacity(divisions.len());
+        for (i, division) in divisions.iter().enumerate() {
+            division_index.entry(division.name.clone()).or_insert(i);
+        }
+
+        Config {
+            options,
+            divisions,
+            weightclasses,
+            exemptions,
+            rulesets,
+            scoring,
+            doc_comments: vec![],
+            aliases: HashMap::new(),
+            parent_federation: None,
+            fourth_attempts: FourthAttemptPolicy::default(),
+            weightclass_index,
+            division_index,
+        }
+    }
+
+    /// Loads the nearest CONFIG.toml at or above `path`, if there is one.
+    ///
+    /// Like `.gitignore` lookup, this walks toward the filesystem root and stops
+    /// at the first CONFIG.toml found, so it works from deep inside a meet folder.
+    /// A CONFIG.toml with errors is itself an error.
+    pub fn from_directory(path: &Path) -> Result<Option<Config>, Box<dyn Error>> {
+        let Some(found) = path
+            .ancestors()
+            .map(|dir| dir.join("CONFIG.toml"))
+            .find(|candidate| candidate.is_file())
+        else {
+            return Ok(None);
+        };
+
+        let result = check_config(found, false)?;
+        let errors = result.report.count_messages().errors();
+        match result.config {
+            Some(config) if errors == 0 => Ok(Some(config)),
+            _ => Err(format!("{} has {errors} error(s)", result.report.path.display()).into()),
+        }
+    }
+
+    /// The configured divisions, in file order.
+    pub fn divisions(&self) -> &[DivisionConfig] {
+        &self.divisions
+    }
+
+    /// The configured weightclass groups, in file order.
+    pub fn weightclasses(&self) -> &[WeightClassConfig] {
+        &self.weightclasses
+    }
+
+    /// Returns the index of the division with the given name or alias.
+    fn division_index_of(&self, name: &str) -> Option<usize> {
+        let name = self.aliases.get(name).map_or(name, String::as_str);
+        self.division_index.get(name).copied()
+    }
+
+    /// Returns the division with the given name or alias.
+    pub fn division_by_name(&self, name: &str) -> Option<&DivisionConfig> {
+        self.division_index_of(name).map(|i| &self.divisions[i])
+    }
+
+    /// Whether a division with the given name or alias is configured.
+    pub fn contains_division(&self, name: &str) -> bool {
+        self.division_index_of(name).is_some()
+    }
+
     /// Returns an optional list of exemptions for the given folder.
     pub fn exemptions_for(&self, meet_folder: &str) -> Option<&[Exemption]> {
         self.exemptions
@@ -121,11 +620,92 @@ impl Config {
             .map(|ec| ec.exemptions.as_slice())
     }
 
+    /// Iterates over the distinct meet folders that have exemptions, in config order.
+    pub fn exempted_meet_folders(&self) -> impl Iterator<Item = &str> {
+        let mut seen = HashSet::new();
+        self.exemptions
+            .iter()
+            .map(|ec| ec.meet_folder.as_str())
+            .filter(move |folder| seen.insert(*folder))
+    }
+
+    /// A lower bound on the number of meets covered by this Config.
+    ///
+    /// Only meets with exemptions are known to the Config, so this is just
+    /// the number of exempted meet folders.
+    pub fn approximate_meet_count(&self) -> usize {
+        self.exempted_meet_folders().count()
+    }
+
+    /// Returns the divisions in the federation's display order.
+    ///
+    /// Divisions with a `sort_order` come first, in that order, followed by
+    /// the rest by name.
+    pub fn sorted_divisions_by_order(&self) -> Vec<&DivisionConfig> {
+        let mut divisions: Vec<&DivisionConfig> = self.divisions.iter().collect();
+        divisions.sort_by_key(|d| (d.sort_order.is_none(), d.sort_order, d.name.as_str()));
+        divisions
+    }
+
+    /// Lists every year in the date ranges of the weightclass sets, in ascending order.
+    ///
+    /// Sets that are still in use count through the year of `today`, or
+    /// through their first year if they start after `today`.
+    pub fn years_covered(&self, today: Date) -> Vec<u32> {
+        let mut years = BTreeSet::new();
+        for wc in &self.weightclasses {
+            for &(min, max) in &wc.date_ranges {
+                let last = if max >= WEIGHTCLASS_DATE_PRESENT {
+                    today.year().max(min.year())
+                } else {
+                    max.year()
+                };
+                years.extend(min.year()..=last);
+            }
+        }
+        years.into_iter().collect()
+    }
+
+    /// The first year covered by a weightclass set, if there are any.
+    pub fn first_year(&self, today: Date) -> Option<u32> {
+        self.years_covered(today).first().copied()
+    }
+
+    /// The last year covered by a weightclass set, if there are any.
+    pub fn last_year(&self, today: Date) -> Option<u32> {
+        self.years_covered(today).last().copied()
+    }
+
+    /// Returns the set of exemptions for the given folder, which may be empty.
+    pub fn exemption_set_for(&self, meet_folder: &str) -> HashSet<Exemption> {
+        self.exemptions
+            .iter()
+            .find(|ec| ec.meet_folder == meet_folder)
+            .map(ExemptionConfig::into_exemption_set)
+            .unwrap_or_default()
+    }
+
+    /// Whether the given folder is exempt from the given check.
+    pub fn is_exempted(&self, meet_folder: &str, exemption: Exemption) -> bool {
+        self.exemptions_for(meet_folder)
+            .is_some_and(|el| el.contains(&exemption))
+    }
+
     /// Returns the valid_since option, if present.
     pub fn valid_since(&self) -> Option<Date> {
         self.options.as_ref()?.valid_since
     }
 
+    /// Returns the federation option, if present.
+    pub fn federation(&self) -> Option<&str> {
+        self.options.as_ref()?.federation.as_deref()
+    }
+
+    /// Returns the scoring section, if present.
+    pub fn scoring(&self) -> Option<&ScoringConfig> {
+        self.scoring.as_ref()
+    }
+
     /// Returns options.require_manual_disambiguation if present, defaulting to
     /// false.
     pub fn does_require_manual_disambiguation(&self) -> bool {
@@ -135,574 +715,5265 @@ impl Config {
             false
         }
     }
-}
 
-fn parse_options(value: &Value, report: &mut Report) -> Option<OptionConfig> {
-    let table = match value.as_table() {
-        Some(t) => t,
-        None => {
-            report.error("Section 'options' must be a Table");
-            return None;
-        }
-    };
+    /// Counts the divisions open to lifters of the given sex,
+    /// including divisions without a sex restriction.
+    pub fn num_divisions_for_sex(&self, sex: Sex) -> usize {
+        self.divisions
+            .iter()
+            .filter(|d| d.sex.is_none_or(|s| s == sex))
+            .count()
+    }
 
-    let valid_since: Option<Date> = if let Some(v) = table.get("valid_since") {
-        match v.as_str().and_then(|s| s.parse::<Date>().ok()) {
-            Some(date) => Some(date),
-            None => {
-                report.error("Value 'valid_since' must be a Date, like '1999-02-24'");
-                None
+    /// Returns the division for a lifter of the given sex and age.
+    ///
+    /// The first ordinary division that admits the lifter is preferred.
+    /// If none does, the catch-all division marked `fallback` is returned.
+    /// Divisions with birth-date cutoffs are skipped, since they need a birth date.
+    pub fn division_for(&self, sex: Sex, age: Age) -> Option<&DivisionConfig> {
+        self.division_for_lifter(sex, age, None)
+    }
+
+    /// Returns the division for a lifter of the given sex, age, and birth date.
+    ///
+    /// Like [`Config::division_for`], but divisions are checked against whichever
+    /// of their age range and birth-date cutoffs are specified.
+    pub fn division_for_lifter(
+        &self,
+        sex: Sex,
+        age: Age,
+        birthdate: Option<Date>,
+    ) -> Option<&DivisionConfig> {
+        let admits_sex = |d: &&DivisionConfig| d.sex.is_none_or(|s| s == sex);
+        self.divisions
+            .iter()
+            .filter(admits_sex)
+            .find(|d| !d.fallback && d.definitely_contains(age, birthdate))
+            .or_else(|| {
+                self.divisions
+                    .iter()
+                    .filter(admits_sex)
+                    .find(|d| d.fallback)
+            })
+    }
+
+    /// Counts the divisions without a sex restriction.
+    pub fn num_divisions_unrestricted(&self) -> usize {
+        self.divisions.iter().filter(|d| d.sex.is_none()).count()
+    }
+
+    /// Returns the weightclass group that applies to an entry.
+    ///
+    /// Groups must match the sex and equipment, and include the date. Groups that
+    /// explicitly list the division are preferred over groups without a division restriction.
+    pub fn effective_weightclasses_for_entry(
+        &self,
+        sex: Sex,
+        equipment: Equipment,
+        date: Date,
+        division_name: Option<&str>,
+    ) -> Option<&WeightClassConfig> {
+        // Look for a group that names the division explicitly.
+        let division = division_name.and_then(|n| self.division_index_of(n));
+        if division.is_some() {
+            if let Some(wc) = self
+                .indexed_weightclasses_for(sex, equipment, date, division)
+                .next()
+            {
+                return Some(wc);
             }
         }
-    } else {
-        None
-    };
 
-    let mut require_manual_disambiguation = false;
-    if let Some(v) = table.get("require_manual_disambiguation") {
-        match v.as_bool() {
-            Some(b) => {
-                require_manual_disambiguation = b;
-            }
-            None => {
-                report.error("Value 'require_manual_disambiguation' must be a boolean");
+        // Fall back to a group without a division restriction.
+        self.indexed_weightclasses_for(sex, equipment, date, None)
+            .next()
+    }
+
+    /// Returns every weightclass group that could apply to an entry.
+    ///
+    /// This is like [`Config::effective_weightclasses_for_entry`], which returns the first
+    /// group, but exposes ambiguous configurations: more than one group is returned only
+    /// if several equally-specific groups match.
+    pub fn matching_weightclasses_for_entry(
+        &self,
+        sex: Sex,
+        equipment: Equipment,
+        date: Date,
+        division_name: Option<&str>,
+    ) -> Vec<&WeightClassConfig> {
+        let division = division_name.and_then(|n| self.division_index_of(n));
+        if division.is_some() {
+            let groups: Vec<&WeightClassConfig> = self
+                .indexed_weightclasses_for(sex, equipment, date, division)
+                .collect();
+            if !groups.is_empty() {
+                return groups;
             }
         }
+        self.indexed_weightclasses_for(sex, equipment, date, None)
+            .collect()
     }
 
-    Some(OptionConfig {
-        valid_since,
-        require_manual_disambiguation,
-    })
-}
-
-fn parse_divisions(value: &Value, report: &mut Report) -> Vec<DivisionConfig> {
-    let mut acc = vec![];
+    /// Binary searches the weightclass index for the groups that include the date.
+    ///
+    /// Groups are returned from the most recently started.
+    fn indexed_weightclasses_for(
+        &self,
+        sex: Sex,
+        equipment: Equipment,
+        date: Date,
+        division: Option<usize>,
+    ) -> impl Iterator<Item = &WeightClassConfig> {
+        let ranges = self
+            .weightclass_index
+            .get(&(sex, division))
+            .map_or(&[][..], Vec::as_slice);
 
-    let table = match value.as_table() {
-        Some(t) => t,
-        None => {
-            report.error("Section 'divisions' must be a Table");
-            return acc;
-        }
-    };
+        // Only ranges that started on or before the date can include it.
+        let end = ranges.partition_point(|&(min, _, _)| min <= date);
+        ranges[..end]
+            .iter()
+            .rev()
+            .filter(move |&&(_, max, _)| date <= max)
+            .map(|&(_, _, i)| &self.weightclasses[i])
+            .filter(move |wc| wc.admits_equipment(equipment))
+    }
 
-    for (key, division) in table {
-        // Parse the division name.
-        let name: &str = match division.get("name").and_then(Value::as_str) {
-            Some(s) => s,
-            None => {
-                report.error(format!("Value '{key}.name' must be a String"));
-                continue;
-            }
+    /// Describes what changed between this Config and the `other` one.
+    pub fn diff(&self, other: &Config) -> ConfigDiff {
+        // Weightclasses refer to divisions by index, so compare their names instead.
+        // Programmatically-built Configs may have invalid indices, which have no name.
+        let division_names = |config: &Config, wc: &WeightClassConfig| {
+            let divisions = wc.divisions.as_ref()?;
+            Some(
+                divisions
+                    .iter()
+                    .map(|&i| config.divisions.get(i).map(|d| d.name.clone()))
+                    .collect::<Vec<Option<String>>>(),
+            )
+        };
+        // Every other field is compared directly. The pattern is exhaustive,
+        // so that adding a field forces a decision about diffing it.
+        let same_weightclasses = |a: &WeightClassConfig, b: &WeightClassConfig| {
+            let WeightClassConfig {
+                name: _,
+                classes,
+                date_ranges,
+                sex,
+                divisions: _,
+                equipment,
+                provisional,
+                source,
+            } = a;
+            *classes == b.classes
+                && *date_ranges == b.date_ranges
+                && *sex == b.sex
+                && *equipment == b.equipment
+                && *provisional == b.provisional
+                && *source == b.source
+                && division_names(self, a) == division_names(other, b)
         };
 
-        // Ensure that the Division name is unique.
-        for already_seen in &acc {
-            if already_seen.name == name {
-                report.error(format!("Division name '{name}' must be unique"));
-                break;
-            }
+        ConfigDiff {
+            divisions: diff_by_name(
+                &self.divisions,
+                &other.divisions,
+                |d| &d.name,
+                |a, b| a == b,
+            ),
+            weightclasses: diff_by_name(
+                &self.weightclasses,
+                &other.weightclasses,
+                |wc| &wc.name,
+                same_weightclasses,
+            ),
+            exemptions: diff_by_name(
+                &self.exemptions,
+                &other.exemptions,
+                |e| &e.meet_folder,
+                |a, b| a.exemptions == b.exemptions,
+            ),
         }
+    }
 
-        // Parse the minimum age.
-        let min_age = match division.get("min") {
-            Some(v) => match v.clone().try_into::<Age>() {
-                Ok(age) => age,
-                Err(e) => {
-                    report.error(format!("Failed parsing {key}.min: {e}"));
+    /// Writes a CSV with one row for each weightclass set that applies to each division.
+    ///
+    /// A set applies to a division if it doesn't exclude the division by sex,
+    /// division restriction, or equipment. Lists are separated by semicolons.
+    pub fn to_csv_summary(&self) -> String {
+        let join = |items: Vec<String>| items.join(";");
+        let mut wtr = csv::Writer::from_writer(vec![]);
+        wtr.write_record([
+            "Division",
+            "MinAge",
+            "MaxAge",
+            "Sex",
+            "Equipment",
+            "WeightClasses",
+            "Classes",
+            "DateMin",
+            "DateMax",
+        ])
+        .expect("writing to a Vec can't fail");
+
+        for (i, division) in self.divisions.iter().enumerate() {
+            for wc in &self.weightclasses {
+                let applies = division.sex.is_none_or(|s| s == wc.sex)
+                    && wc.divisions.as_ref().is_none_or(|d| d.contains(&i))
+                    && match (&division.equipment, &wc.equipment) {
+                        (Some(a), Some(b)) => a.iter().any(|e| b.contains(e)),
+                        _ => true,
+                    };
+                if !applies {
                     continue;
                 }
-            },
-            None => {
-                report.error(format!("Division '{key}' is missing the property 'min'"));
+
+                // A set with several date ranges gets one row per range.
+                for (date_min, date_max) in &wc.date_ranges {
+                    let equipment = division.equipment.iter().flatten().map(|e| e.to_string());
+                    wtr.write_record([
+                        division.name.clone(),
+                        division.min.to_string(),
+                        division.max.to_string(),
+                        wc.sex.to_string(),
+                        join(equipment.collect()),
+                        wc.name.clone(),
+                        join(wc.classes.iter().map(|c| c.to_string()).collect()),
+                        date_min.to_string(),
+                        date_max.to_string(),
+                    ])
+                    .expect("writing to a Vec can't fail");
+                }
+            }
+        }
+
+        let bytes = wtr.into_inner().expect("writing to a Vec can't fail");
+        String::from_utf8(bytes).expect("all fields are UTF-8")
+    }
+
+    /// Draws a table for each sex showing which weightclass set each division
+    /// gets in each era, for reviewing a whole config at a glance.
+    ///
+    /// Eras are split wherever one of the sex's sets starts or stops. Each cell
+    /// is resolved like an entry on the era's first day, using the division's
+    /// first equipment (or Raw), and is `-` if no set applies.
+    pub fn coverage_report(&self) -> String {
+        let mut out = String::new();
+        for sex in [Sex::M, Sex::F, Sex::Mx] {
+            let ranges: Vec<(Date, Date)> = self
+                .weightclasses
+                .iter()
+                .filter(|wc| wc.sex == sex)
+                .flat_map(|wc| wc.date_ranges.iter().copied())
+                .collect();
+            if ranges.is_empty() {
                 continue;
             }
-        };
 
-        // Parse the maximum age.
-        let max_age = match division.get("max") {
-            Some(v) => match v.clone().try_into::<Age>() {
-                Ok(age) => age,
-                Err(e) => {
-                    report.error(format!("Failed parsing {key}.max: {e}"));
+            let boundaries: BTreeSet<Date> = ranges
+                .iter()
+                .flat_map(|&(min, max)| [min, max.add_days(1)])
+                .collect();
+            let boundaries: Vec<Date> = boundaries.into_iter().collect();
+            let eras: Vec<(Date, Date)> = boundaries
+                .windows(2)
+                .map(|w| (w[0], w[1].add_days(-1)))
+                .filter(|&(start, _)| {
+                    ranges
+                        .iter()
+                        .any(|&(min, max)| min <= start && start <= max)
+                })
+                .collect();
+
+            let mut header = vec!["Division".to_string(), "Ages".to_string()];
+            header.extend(eras.iter().map(|&(start, end)| {
+                if end >= WEIGHTCLASS_DATE_PRESENT {
+                    format!("{start}–present")
+                } else {
+                    format!("{start}–{end}")
+                }
+            }));
+            let mut rows = vec![header];
+
+            for division in self.sorted_divisions_by_order() {
+                if division.sex.is_some_and(|s| s != sex) {
                     continue;
                 }
-            },
-            None => {
-                report.error(format!("Division '{key}' is missing the property 'max'"));
-                continue;
+                let equipment = division
+                    .equipment
+                    .as_ref()
+                    .and_then(|e| e.first().copied())
+                    .unwrap_or(Equipment::Raw);
+                let mut ages = if !division.has_age_bounds() {
+                    String::new()
+                } else if division.max.is_unbounded_max() {
+                    format!("{}+", division.min)
+                } else {
+                    format!("{}–{}", division.min, division.max)
+                };
+                if division.has_birth_date_bounds() {
+                    if !ages.is_empty() {
+                        ages.push_str(", ");
+                    }
+                    ages.push_str(&division.birth_date_bounds_string());
+                }
+
+                let mut row = vec![division.name.clone(), ages];
+                row.extend(eras.iter().map(|&(start, _)| {
+                    self.effective_weightclasses_for_entry(
+                        sex,
+                        equipment,
+                        start,
+                        Some(&division.name),
+                    )
+                    .map_or_else(|| "-".to_string(), |wc| wc.name.clone())
+                }));
+                rows.push(row);
             }
-        };
 
-        // TODO: This fixes the case of {9.5, 10.5}, where is_definitely_less_than
-        // fails. TODO: But it could be less of a hack. Maybe define PartialOrd?
-        let mut valid_approximate_ages = false;
-        if let Age::Approximate(a) = min_age {
-            if let Age::Approximate(b) = max_age {
-                if a < b {
-                    valid_approximate_ages = true;
+            let mut widths = vec![0; rows[0].len()];
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
                 }
             }
+
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("Sex: {sex}\n"));
+            for row in rows {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| format!("{cell:width$}"))
+                    .collect();
+                out.push_str(cells.join("  ").trim_end());
+                out.push('\n');
+            }
         }
+        out
+    }
 
-        // The age range must be nonmonotonically increasing.
-        if min_age != max_age
-            && !min_age.is_definitely_less_than(max_age)
-            && !valid_approximate_ages
-        {
-            report.error(format!(
-                "Division '{key}' has an invalid age range '{min_age}-{max_age}'"
-            ));
-            continue;
+    /// Lints for tested divisions without an untested counterpart.
+    ///
+    /// Some federations mirror every division into tested and untested variants,
+    /// so a tested division with nothing matching its ages, sex, equipment, and
+    /// age mode among the untested ones was probably added alone by mistake.
+    /// Federations without any untested divisions are fully tested, so they're
+    /// not checked, and neither are divisions for guests or records only.
+    ///
+    /// Plenty of federations only offer some divisions tested, so it's opt-in.
+    pub fn tested_division_warnings(&self) -> Vec<String> {
+        let is_checked = |d: &&DivisionConfig| d.place.is_none() && d.records_only != Some(true);
+        let (tested, untested): (Vec<&DivisionConfig>, Vec<&DivisionConfig>) = self
+            .divisions
+            .iter()
+            .filter(is_checked)
+            .partition(|d| d.tested == Some(true));
+        if untested.is_empty() {
+            return vec![];
         }
 
-        // An optional sex restriction may be provided.
-        let sex: Option<Sex> = match division.get("sex") {
-            Some(v) => match v.clone().try_into::<Sex>() {
-                Ok(sex) => Some(sex),
-                Err(e) => {
-                    report.error(format!("Failed parsing {key}.sex: {e}"));
-                    None
-                }
-            },
-            None => None,
-        };
+        let mut acc = vec![];
+        for division in tested {
+            let mirrored = untested.iter().any(|u| {
+                u.min == division.min
+                    && u.max == division.max
+                    && u.born_after == division.born_after
+                    && u.born_before == division.born_before
+                    && u.sex == division.sex
+                    && u.equipment == division.equipment
+                    && u.age_mode == division.age_mode
+            });
+            if !mirrored {
+                acc.push(format!(
+                    "Tested division '{}' has no untested counterpart \
+                     with the same ages, sex, and equipment",
+                    division.name
+                ));
+            }
+        }
+        acc
+    }
 
-        // An optional list of allowed equipment may be provided.
-        let equipment: Option<Vec<Equipment>> = match division.get("equipment") {
-            Some(v) => {
-                if let Some(array) = v.as_array() {
-                    if array.is_empty() {
-                        report.error(format!("{key}.equipment cannot be empty"));
-                    }
+    /// Lints for weightclass sets that aren't a subset of the parent federation's.
+    ///
+    /// Each set must only use classes from one of the parent's sets for the same
+    /// sex that was in use at some of the same time. Sets without any such
+    /// parent set aren't checked, since the parent's history may be incomplete.
+    pub fn parent_weightclass_warnings(&self, parent: &Config) -> Vec<String> {
+        let mut acc = vec![];
+        for set in &self.weightclasses {
+            // For each parent set in use at the same time, the classes it lacks.
+            let missing: Vec<Vec<&WeightClassKg>> = parent
+                .weightclasses
+                .iter()
+                .filter(|p| p.sex == set.sex && p.overlaps(set))
+                .map(|p| {
+                    set.classes
+                        .iter()
+                        .filter(|c| !p.classes.contains(c))
+                        .collect()
+                })
+                .collect();
 
-                    let mut vec = Vec::with_capacity(array.len());
-                    for value in array {
-                        match value.clone().try_into::<Equipment>() {
-                            Ok(equipment) => {
-                                vec.push(equipment);
-                            }
-                            Err(e) => {
-                                report.error(format!("Error in {key}.equipment: {e}"));
-                            }
-                        }
-                    }
-                    Some(vec)
-                } else if let Some(s) = v.as_str() {
-                    match s.parse::<Equipment>() {
-                        Ok(equipment) => Some(vec![equipment]),
-                        Err(e) => {
-                            report.error(format!("Error in {key}.equipment: {e}"));
-                            None
-                        }
-                    }
-                } else {
-                    report.error(format!("{key}.equipment must be a sting or array"));
-                    None
+            if let Some(fewest) = missing.iter().min_by_key(|m| m.len()) {
+                if !fewest.is_empty() {
+                    let classes: Vec<String> = fewest.iter().map(|c| c.to_string()).collect();
+                    acc.push(format!(
+                        "{set} has classes [{}] that the parent federation doesn't use then",
+                        classes.join(", ")
+                    ));
                 }
             }
-            None => None,
-        };
+        }
+        acc
+    }
 
-        // Provides a Tested flag which sets some divisions as default-Tested.
-        let tested: Option<bool> = match division.get("tested").and_then(Value::as_str) {
-            Some(v) => match v {
-                "Yes" => Some(true),
-                "No" => Some(false),
-                _ => {
-                    report.error(format!("Failed parsing {key}.tested: invalid '{v}'"));
-                    None
-                }
-            },
-            None => None,
-        };
+    /// Lints for weightclass sets that don't say where their classes came from.
+    ///
+    /// Archived federations are easier to maintain when each set cites a rulebook,
+    /// but most existing sets don't, so it's opt-in.
+    pub fn weightclass_source_warnings(&self) -> Vec<String> {
+        self.weightclasses
+            .iter()
+            .filter(|wc| wc.source.is_none())
+            .map(|wc| format!("{} has no source: cite the rulebook or URL", wc.name))
+            .collect()
+    }
 
-        // Provides a Place value that all entries in the Division must have.
-        // This is used to enforce Guest divisions being marked Guest.
-        let place: Option<Place> = match division.get("place").and_then(Value::as_str) {
-            Some(s) => match s.parse::<Place>() {
-                Ok(p) => Some(p),
-                Err(e) => {
-                    report.error(format!("Failed parsing {key}.place: {e}"));
-                    None
+    /// Lints for sets of different sexes with identical classes in use at the same time.
+    ///
+    /// Real ladders almost always differ by sex, so an identical ladder was
+    /// usually copied from another set without review. Some federations do
+    /// give an Mx category the men's ladder on purpose, so it's opt-in.
+    pub fn identical_ladder_warnings(&self) -> Vec<String> {
+        let mut acc = vec![];
+        for (n, a) in self.weightclasses.iter().enumerate() {
+            for b in &self.weightclasses[n + 1..] {
+                if a.sex != b.sex
+                    && !a.classes.is_empty()
+                    && a.classes == b.classes
+                    && a.overlaps(b)
+                {
+                    acc.push(format!(
+                        "{a} for sex '{}' and {b} for sex '{}' have identical classes: \
+                         was one copied without review?",
+                        a.sex, b.sex
+                    ));
                 }
-            },
-            None => None,
-        };
-
-        acc.push(DivisionConfig {
-            name: name.to_string(),
-            min: min_age,
-            max: max_age,
-            sex,
-            equipment,
-            tested,
-            place,
-        });
+            }
+        }
+        acc
     }
 
-    acc
-}
-
-fn parse_weightclasses(
-    value: &Value,
-    divisions: &[DivisionConfig],
-    report: &mut Report,
-) -> Vec<WeightClassConfig> {
-    let mut acc = vec![];
-
-    let table = match value.as_table() {
-        Some(t) => t,
-        None => {
-            report.error("Section 'weightclasses' must be a Table");
-            return acc;
+    /// Runs every heuristic lint, which are opt-in since they produce false positives.
+    ///
+    /// Run by [`Config::validate`] with `heuristics` set, and by `--verbose`.
+    pub fn heuristic_warnings(&self) -> Vec<String> {
+        let mut acc = self.weightclass_neighbor_warnings();
+        acc.extend(self.tested_division_warnings());
+        acc.extend(self.identical_ladder_warnings());
+        acc.extend(self.weightclass_source_warnings());
+        for wc in &self.weightclasses {
+            acc.extend(weightclass_gap_warnings(&wc.name, &wc.classes));
         }
-    };
+        // Youth sets legitimately start light, and masters sets may start heavy.
+        for wc in self
+            .weightclasses
+            .iter()
+            .filter(|wc| wc.divisions.is_none())
+        {
+            acc.extend(weightclass_floor_warning(&wc.name, wc.sex, &wc.classes));
+        }
+        acc.extend(weightclass_count_warnings(&self.weightclasses));
+        acc.extend(division_equipment_name_warnings(&self.divisions));
+        for wc in &self.weightclasses {
+            let for_divisions = wc.divisions.is_some();
+            acc.extend(weightclass_sex_warning(
+                &wc.name,
+                wc.sex,
+                &wc.classes,
+                for_divisions,
+            ));
+        }
+        acc
+    }
 
-    for (key, weightclass) in table {
-        // Parse the list of weightclasses.
-        let classes = match weightclass.get("classes").and_then(Value::as_array) {
-            Some(array) => {
-                let mut vec = Vec::with_capacity(array.len());
-                for value in array {
-                    match value.clone().try_into::<WeightClassKg>() {
-                        Ok(class) => {
-                            vec.push(class);
-                        }
-                        Err(e) => {
-                            report.error(format!("Error in '{key}.classes': {e}"));
-                        }
-                    }
-                }
-                vec
-            }
-            None => {
-                report.error(format!("Value '{key}.classes' must be an Array"));
-                continue;
-            }
+    /// Lints for classes whose neighbors differ between sets in use at the same time.
+    ///
+    /// Ladders change between eras, so only sets of the same sex with overlapping
+    /// date ranges are compared. Youth or equipment-specific sets often add or drop
+    /// classes at the ends of a ladder, so a class shared by two sets is only
+    /// suspicious when both its lower and its upper neighbor differ.
+    ///
+    /// This is a heuristic that produces false positives, so it's opt-in.
+    pub fn weightclass_neighbor_warnings(&self) -> Vec<String> {
+        let neighbors = |classes: &[WeightClassKg], i: usize| {
+            let lower = i.checked_sub(1).map(|j| classes[j]);
+            (lower, classes.get(i + 1).copied())
         };
 
-        // Parse the min and max dates.
-        let date_range = match weightclass.get("date_range").and_then(Value::as_array) {
-            Some(array) => {
-                if array.len() != 2 {
-                    report.error(format!("Array '{key}.date_range' must have 2 items"));
+        let mut acc = vec![];
+        for (n, a) in self.weightclasses.iter().enumerate() {
+            for b in &self.weightclasses[n + 1..] {
+                if a.sex != b.sex || !a.overlaps(b) {
                     continue;
                 }
-                // TODO: These clone() calls can be removed by using Value::as_str().
-                let date_min = match array[0].clone().try_into::<Date>() {
-                    Ok(date) => date,
-                    Err(e) => {
-                        report.error(format!("Error in '{key}.date_range': {e}"));
+
+                for (i, class) in a.classes.iter().enumerate() {
+                    let Some(j) = b.classes.iter().position(|c| c == class) else {
                         continue;
-                    }
-                };
-                let date_max = match array[1].clone().try_into::<Date>() {
-                    Ok(date) => date,
-                    Err(e) => {
-                        report.error(format!("Error in '{key}.date_range': {e}"));
+                    };
+                    let (a_lower, a_upper) = neighbors(&a.classes, i);
+                    let (b_lower, b_upper) = neighbors(&b.classes, j);
+                    let (Some(a_lower), Some(a_upper), Some(b_lower), Some(b_upper)) =
+                        (a_lower, a_upper, b_lower, b_upper)
+                    else {
                         continue;
+                    };
+                    if a_lower != b_lower && a_upper != b_upper {
+                        acc.push(format!(
+                            "WeightClassKg '{class}' is between '{a_lower}' and '{a_upper}' \
+                             in {a}, but between '{b_lower}' and '{b_upper}' in {b}"
+                        ));
                     }
-                };
-                (date_min, date_max)
+                }
             }
-            None => {
-                report.error(format!("Value '{key}.date_range' must be an Array"));
-                continue;
+        }
+        acc
+    }
+
+    /// Re-runs the structural checks performed while parsing a CONFIG.toml.
+    ///
+    /// This is useful for a `Config` that was constructed programmatically,
+    /// since no `Report` is available to collect the errors.
+    pub fn sanity_check(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        for (i, division) in self.divisions.iter().enumerate() {
+            let name = &division.name;
+
+            // Ensure that the Division name is unique.
+            if self.divisions[..i].iter().any(|d| d.name == *name) {
+                errors.push(format!("Division name '{name}' must be unique"));
             }
-        };
 
-        // Parse the sex restriction.
-        let sex = match weightclass.get("sex").and_then(Value::as_str) {
-            Some(s) => match s.parse::<Sex>() {
-                Ok(sex) => sex,
-                Err(e) => {
-                    report.error(format!("Error in '{key}.sex': {e}"));
-                    continue;
-                }
-            },
-            None => {
-                report.error(format!("Value '{key}.sex' must be a String"));
-                continue;
+            // The age range must be nonmonotonically increasing.
+            let (min_age, max_age) = (division.min, division.max);
+            if !division.has_age_bounds() && !division.has_birth_date_bounds() {
+                errors.push(format!(
+                    "Division '{name}' has no age range: use 'min = 0' and 'max = 999'"
+                ));
+            } else if min_age.is_some() && max_age.is_some() && min_age > max_age {
+                errors.push(format!(
+                    "Division '{name}' has an invalid age range '{min_age}-{max_age}'"
+                ));
             }
-        };
+            errors.extend(birth_date_cutoff_error(
+                name,
+                division.born_after,
+                division.born_before,
+            ));
 
-        // Parse the optional division restriction.
-        let divindices: Option<Vec<usize>> = match weightclass.get("divisions") {
-            Some(v) => match v.as_array() {
-                Some(a) => {
-                    let mut vec = Vec::with_capacity(a.len());
-                    for div in a {
-                        match div.as_str() {
-                            Some(div) => match divisions.iter().position(|r| r.name == div) {
-                                Some(idx) => vec.push(idx),
-                                None => {
-                                    report.error(format!(
-                                        "Invalid division '{div}' in {key}.divisions"
-                                    ));
-                                    continue;
-                                }
-                            },
-                            None => {
-                                report.error(format!(
-                                    "Array '{key}.divisions' may only contain Strings"
-                                ));
-                                continue;
-                            }
-                        }
-                    }
-                    Some(vec)
-                }
-                None => {
-                    report.error(format!("Value '{key}.divisions' must be an Array"));
-                    continue;
+            if division.equipment.as_ref().is_some_and(Vec::is_empty) {
+                errors.push(format!("{name}.equipment cannot be empty"));
+            }
+        }
+
+        for weightclass in &self.weightclasses {
+            let key = &weightclass.name;
+            errors.extend(weightclass_ordering_errors(key, &weightclass.classes));
+
+            // Division restrictions are indices into the divisions list.
+            for &idx in weightclass.divisions.iter().flatten() {
+                if idx >= self.divisions.len() {
+                    errors.push(format!("Invalid division index '{idx}' in {key}.divisions"));
                 }
-            },
-            None => None,
-        };
+            }
 
-        // The classes must be ordered from least to greatest.
-        // This ordering is required for the logic in check_weightclass_consistency.
-        for i in 1..classes.len() {
-            if classes[i - 1] >= classes[i] {
-                report.error(format!(
-                    "WeightClassKg '{}' occurs before '{}' in [weightclasses.{}]",
-                    classes[i - 1],
-                    classes[i],
-                    key
-                ));
+            if weightclass.equipment.as_ref().is_some_and(Vec::is_empty) {
+                errors.push(format!("{key}.equipment cannot be empty"));
             }
         }
 
-        acc.push(WeightClassConfig {
-            name: key.to_string(),
-            classes,
-            date_min: date_range.0,
-            date_max: date_range.1,
-            sex,
-            divisions: divindices,
-        });
+        for exemption in self.exemptions.iter().filter(|e| !e.is_valid()) {
+            errors.push(format!(
+                "Exemption for folder '{}' needs a folder and at least one exemption",
+                exemption.meet_folder
+            ));
+        }
+
+        errors
     }
 
-    acc
-}
+    /// Runs every check that looks across the whole Config, after parsing.
+    ///
+    /// Parsing already runs this with the default options. Configs built
+    /// programmatically should also enable `structural`.
+    pub fn validate(&self, opts: ValidateOptions, report: &mut Report) {
+        if opts.structural {
+            for error in self.sanity_check() {
+                report.error(error);
+            }
+        }
 
-fn parse_rulesets(value: &Value, report: &mut Report) -> Vec<RuleSetConfig> {
+        check_exemption_applicability(&self.divisions, &self.exemptions, report);
+
+        if opts.heuristics {
+            for warning in self.heuristic_warnings() {
+                report.warning(warning);
+            }
+        }
+    }
+
+    /// Whether the Config passes all of the checks in [`Config::sanity_check`].
+    ///
+    /// Intended as a quick pre-flight check before validating entries against it.
+    pub fn is_consistent(&self) -> bool {
+        self.sanity_check().is_empty()
+    }
+
+    /// Panics, listing every problem found, if the Config is not consistent.
+    pub fn assert_consistent(&self) {
+        let errors = self.sanity_check();
+        assert!(
+            errors.is_empty(),
+            "Config is not consistent:\n  {}",
+            errors.join("\n  ")
+        );
+    }
+}
+
+impl DivisionConfig {
+    /// Lists the allowed equipment, like "Raw, Wraps", or "Any" if unrestricted.
+    pub fn equipment_list_string(&self) -> String {
+        match &self.equipment {
+            Some(list) => list
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            None => "Any".to_string(),
+        }
+    }
+
+    /// Converts a lifter's Age on the meet date to the age used by this division.
+    ///
+    /// In `AgeMode::BirthYear`, the lifter is as old as they will be at the end of
+    /// the year. An `Age::Exact(n)` lifter may not have had their birthday yet,
+    /// so is `Age::Approximate(n)` ("n or n+1"). An `Age::Approximate(n)`, which is
+    /// itself derived from a BirthYear, becomes `Age::Exact(n + 1)`.
+    pub fn effective_age(&self, age: Age) -> Age {
+        match (self.age_mode, age) {
+            (AgeMode::Age, _) | (_, Age::None) => age,
+            (AgeMode::BirthYear, Age::Exact(n)) => Age::Approximate(n),
+            (AgeMode::BirthYear, Age::Approximate(n)) => Age::Exact(n.saturating_add(1)),
+        }
+    }
+
+    /// Whether the division has a min or max age.
+    ///
+    /// Divisions with birth-date cutoffs may leave out the age range.
+    pub fn has_age_bounds(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    /// Whether the division has a `born_after` or `born_before` cutoff.
+    pub fn has_birth_date_bounds(&self) -> bool {
+        self.born_after.is_some() || self.born_before.is_some()
+    }
+
+    /// Whether a lifter born on the given date is within the birth-date cutoffs.
+    ///
+    /// Both cutoffs are exclusive, so `born_after = "2005-12-31"` admits lifters
+    /// born on 2006-01-01. A division without cutoffs admits every birth date.
+    pub fn birth_date_contains(&self, birthdate: Date) -> bool {
+        self.born_after.is_none_or(|after| birthdate > after)
+            && self.born_before.is_none_or(|before| birthdate < before)
+    }
+
+    /// Describes the birth-date cutoffs, like "born after 2005-12-31".
+    fn birth_date_bounds_string(&self) -> String {
+        match (self.born_after, self.born_before) {
+            (Some(after), Some(before)) => format!("born after {after}, before {before}"),
+            (Some(after), None) => format!("born after {after}"),
+            (None, Some(before)) => format!("born before {before}"),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// Whether a lifter definitely belongs in the division by age and birth date.
+    ///
+    /// Only the bounds that the division specifies are checked, but those
+    /// must be satisfied: an unknown birth date doesn't meet a cutoff.
+    fn definitely_contains(&self, age: Age, birthdate: Option<Date>) -> bool {
+        let by_age = !self.has_age_bounds() || self.age_contains(self.effective_age(age));
+        let by_birth_date =
+            !self.has_birth_date_bounds() || birthdate.is_some_and(|b| self.birth_date_contains(b));
+        by_age && by_birth_date
+    }
+
+    /// Whether a lifter of the given Age definitely belongs in this division's age range.
+    ///
+    /// Approximate ages are treated conservatively: both possible ages must be
+    /// in range. An `Age::Approximate(40)` ("40 or 41") is therefore contained
+    /// in 38-42, but not in 41-45.
+    ///
+    /// Approximate bounds, used for BirthYear-based divisions, cover both of
+    /// their ages: a range of 19.5-22.5 contains the ages 19 through 23.
+    /// A missing bound leaves that side of the range open.
+    ///
+    /// An `Age::None` is never contained, since nothing is known about it.
+    pub fn age_contains(&self, age: Age) -> bool {
+        // The inclusive range of ages that the lifter could be.
+        let (low, high) = match age {
+            Age::Exact(n) => (n, n),
+            Age::Approximate(n) => (n, n.saturating_add(1)),
+            Age::None => return false,
+        };
+
+        let above_min = match self.min {
+            Age::Exact(min) | Age::Approximate(min) => low >= min,
+            Age::None => true,
+        };
+        let below_max = match self.max {
+            Age::Exact(max) => high <= max,
+            Age::Approximate(max) => high <= max.saturating_add(1),
+            Age::None => true,
+        };
+        above_min && below_max
+    }
+}
+
+/// Describes a pair of birth-date cutoffs that no birth date falls between, if they are.
+///
+/// Both cutoffs are exclusive, so they must be at least two days apart.
+fn birth_date_cutoff_error(
+    name: &str,
+    born_after: Option<Date>,
+    born_before: Option<Date>,
+) -> Option<String> {
+    let (after, before) = (born_after?, born_before?);
+    (after.add_days(1) >= before).then(|| {
+        format!(
+            "Division '{name}' is for lifters born after {after} and before {before}, \
+             which admits nobody"
+        )
+    })
+}
+
+/// Describes each pair of classes that is not ordered from least to greatest.
+///
+/// Exact duplicates get their own message, since "occurs before" reads oddly for them.
+fn weightclass_ordering_errors(key: &str, classes: &[WeightClassKg]) -> Vec<String> {
+    classes
+        .windows(2)
+        .filter(|pair| pair[0] >= pair[1])
+        .map(|pair| {
+            if pair[0] == pair[1] {
+                format!(
+                    "WeightClassKg '{}' is duplicated in [weightclasses.{}]",
+                    pair[0], key
+                )
+            } else {
+                format!(
+                    "WeightClassKg '{}' occurs before '{}' in [weightclasses.{}]",
+                    pair[0], pair[1], key
+                )
+            }
+        })
+        .collect()
+}
+
+/// Consecutive classes further apart than this suggest that a class was dropped.
+const WEIGHTCLASS_GAP_MAX_KG: WeightKg = WeightKg::from_i32(25);
+
+/// Consecutive classes with a greater ratio than this suggest that a class was dropped.
+const WEIGHTCLASS_GAP_MAX_RATIO: f32 = 1.3;
+
+/// Describes each pair of adjacent classes with an implausibly large gap.
+///
+/// Real ladders are gradual, but this is only a heuristic, so it's opt-in.
+/// The open `+` class is exempt, since it has no upper bound.
+fn weightclass_gap_warnings(key: &str, classes: &[WeightClassKg]) -> Vec<String> {
+    classes
+        .windows(2)
+        .filter_map(|pair| match (pair[0], pair[1]) {
+            (WeightClassKg::UnderOrEqual(a), WeightClassKg::UnderOrEqual(b)) if a < b => {
+                Some((a, b))
+            }
+            _ => None,
+        })
+        .filter(|&(a, b)| {
+            b - a > WEIGHTCLASS_GAP_MAX_KG
+                || f32::from(b) > f32::from(a) * WEIGHTCLASS_GAP_MAX_RATIO
+        })
+        .map(|(a, b)| {
+            format!(
+                "WeightClassKg '{a}' is followed by '{b}' in [weightclasses.{key}], a gap of {}kg: \
+                 is a class missing?",
+                b - a
+            )
+        })
+        .collect()
+}
+
+/// Men's sets whose lightest class is above this weight may be missing a class.
+const MENS_BOTTOM_CLASS_MAX_KG: WeightKg = WeightKg::from_i32(60);
+
+/// Women's sets whose lightest class is above this weight may be missing a class.
+const WOMENS_BOTTOM_CLASS_MAX_KG: WeightKg = WeightKg::from_i32(47);
+
+/// Heuristically checks that a set of classes has an entry-level class.
+///
+/// A set that starts too heavy excludes small lifters, which usually means
+/// that the bottom of the set was truncated. Only the bounded classes are
+/// considered, since `+` classes are never the lightest in a real set.
+///
+/// Only applies to sets without a division restriction, and is only run
+/// as part of [`Config::heuristic_warnings`].
+fn weightclass_floor_warning(key: &str, sex: Sex, classes: &[WeightClassKg]) -> Option<String> {
+    let floor = match sex {
+        Sex::M => MENS_BOTTOM_CLASS_MAX_KG,
+        Sex::F => WOMENS_BOTTOM_CLASS_MAX_KG,
+        Sex::Mx => return None,
+    };
+    let lightest = classes
+        .iter()
+        .filter_map(|c| match *c {
+            WeightClassKg::UnderOrEqual(w) => Some(w),
+            _ => None,
+        })
+        .min()?;
+
+    (lightest > floor).then(|| {
+        format!(
+            "[weightclasses.{key}] starts at {lightest}kg, above the usual {floor}kg: \
+             is a class missing at the bottom?"
+        )
+    })
+}
+
+/// Women's classes above this weight suggest a men's set copied under `sex = "F"`.
+const WOMENS_CLASS_MAX_KG: WeightKg = WeightKg::from_i32(125);
+
+/// Men's sets topping out below this weight suggest a women's set under `sex = "M"`.
+///
+/// Youth sets can legitimately top out lower, so this only applies to sets
+/// without a division restriction.
+const MENS_TOP_CLASS_MIN_KG: WeightKg = WeightKg::from_i32(75);
+
+/// Heuristically checks that a set of classes looks like it is for the given sex.
+///
+/// Men's and women's sets have characteristically different top classes,
+/// but federations vary, so this is only run as part of [`Config::heuristic_warnings`].
+fn weightclass_sex_warning(
+    key: &str,
+    sex: Sex,
+    classes: &[WeightClassKg],
+    for_divisions: bool,
+) -> Option<String> {
+    let top = classes
+        .iter()
+        .filter_map(|c| match *c {
+            WeightClassKg::UnderOrEqual(w) | WeightClassKg::Over(w) => Some(w),
+            WeightClassKg::None => None,
+        })
+        .max()?;
+
+    match sex {
+        Sex::F if top > WOMENS_CLASS_MAX_KG => Some(format!(
+            "[weightclasses.{key}] is for women, but has a class of {top}kg"
+        )),
+        Sex::M if !for_divisions && top < MENS_TOP_CLASS_MIN_KG => Some(format!(
+            "[weightclasses.{key}] is for men, but the top class is only {top}kg"
+        )),
+        _ => None,
+    }
+}
+
+/// Describes each women's set with more classes than a men's set in effect at the same time.
+///
+/// Men's ladders almost always have at least as many classes as women's, so
+/// the reverse usually means the sets were entered under the wrong sex.
+/// Only sets for the same divisions and equipment with overlapping date ranges are compared.
+/// Federations do occasionally invert the counts, so this is only a heuristic.
+fn weightclass_count_warnings(sets: &[WeightClassConfig]) -> Vec<String> {
     let mut acc = vec![];
+    for men in sets.iter().filter(|wc| wc.sex == Sex::M) {
+        for women in sets.iter().filter(|wc| wc.sex == Sex::F) {
+            if men.overlaps(women)
+                && men.divisions == women.divisions
+                && men.equipment == women.equipment
+                && women.class_count() > men.class_count()
+            {
+                acc.push(format!(
+                    "{women} has {} classes for women, but {men} has only {} for men: \
+                     are the sexes swapped?",
+                    women.class_count(),
+                    men.class_count()
+                ));
+            }
+        }
+    }
+    acc
+}
 
+fn parse_options(value: &Value, report: &mut Report) -> Option<OptionConfig> {
     let table = match value.as_table() {
         Some(t) => t,
         None => {
-            report.error("Section 'rulesets' must be a Table");
-            return acc;
+            report.error("Section 'options' must be a Table");
+            return None;
         }
     };
 
-    for (key, section) in table {
-        // Parse the list of rulesets.
-        let ruleset = match section.get("ruleset").and_then(Value::as_array) {
-            Some(array) => {
-                let mut ruleset = RuleSet::default();
-                for value in array {
-                    match value.clone().try_into::<Rule>() {
-                        Ok(rule) => {
-                            ruleset.add(rule);
-                        }
-                        Err(e) => {
-                            report.error(format!("Error in '{key}.ruleset': {e}"));
-                        }
-                    }
-                }
-                ruleset
+    let valid_since: Option<Date> = if let Some(v) = table.get("valid_since") {
+        match v.as_str().and_then(|s| s.parse::<Date>().ok()) {
+            Some(date) if date.is_valid() => Some(date),
+            Some(date) => {
+                report.error(format!(
+                    "Value 'valid_since' is {date}, which does not exist in the Gregorian calendar"
+                ));
+                None
             }
             None => {
-                report.error(format!("Value '{key}.ruleset' must be an Array"));
-                continue;
+                report.error("Value 'valid_since' must be a Date, like '1999-02-24'");
+                None
             }
-        };
+        }
+    } else {
+        None
+    };
 
-        // Parse the min and max dates.
-        let date_range = match section.get("date_range").and_then(Value::as_array) {
-            Some(array) => {
-                if array.len() != 2 {
-                    report.error(format!("Array '{key}.date_range' must have 2 items"));
-                    continue;
-                }
-                // TODO: These clone() calls can be removed by using Value::as_str().
-                let date_min = match array[0].clone().try_into::<Date>() {
-                    Ok(date) => date,
-                    Err(e) => {
-                        report.error(format!("Error in '{key}.date_range': {e}"));
-                        continue;
-                    }
-                };
-                let date_max = match array[1].clone().try_into::<Date>() {
-                    Ok(date) => date,
-                    Err(e) => {
-                        report.error(format!("Error in '{key}.date_range': {e}"));
-                        continue;
-                    }
-                };
-                (date_min, date_max)
+    let mut require_manual_disambiguation = false;
+    if let Some(v) = table.get("require_manual_disambiguation") {
+        match v.as_bool() {
+            Some(b) => {
+                require_manual_disambiguation = b;
             }
             None => {
-                report.error(format!("Value '{key}.date_range' must be an Array"));
-                continue;
+                report.error("Value 'require_manual_disambiguation' must be a boolean");
             }
-        };
-
-        acc.push(RuleSetConfig {
-            ruleset,
-            date_min: date_range.0,
-            date_max: date_range.1,
-        });
+        }
     }
 
-    acc
+    let federation: Option<String> = if let Some(v) = table.get("federation") {
+        match v.as_str() {
+            Some(s) => Some(s.to_string()),
+            None => {
+                report.error("Value 'federation' must be a String");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    Some(OptionConfig {
+        valid_since,
+        require_manual_disambiguation,
+        federation,
+    })
 }
 
-fn parse_exemptions(value: &Value, report: &mut Report) -> Vec<ExemptionConfig> {
-    let mut acc = vec![];
+/// Parses the optional "aliases" table, which maps alternate division names
+/// to the divisions they stand for, like `"Elite" = "Open"`.
+fn parse_aliases(
+    value: &Value,
+    divisions: &[DivisionConfig],
+    report: &mut Report,
+) -> HashMap<String, String> {
+    let mut acc = HashMap::new();
 
     let table = match value.as_table() {
         Some(t) => t,
         None => {
-            report.error("Section 'exemptions' must be a Table");
+            report.error("Section 'aliases' must be a Table");
             return acc;
         }
     };
 
-    for (key, exemptions) in table {
-        let exemptions = match exemptions.as_array() {
-            Some(a) => a,
+    for (alias, target) in table {
+        let target = match target.as_str() {
+            Some(s) => s,
             None => {
-                report.error(format!("exemptions.{key} must be an Array"));
+                report.error(format!("Alias '{alias}' must be a String"));
                 continue;
             }
         };
 
-        let mut vec = Vec::with_capacity(exemptions.len());
-        for exemption in exemptions {
-            let s = match exemption.as_str() {
-                Some(s) => s,
-                None => {
-                    report.error(format!("exemptions.{key} must contain Strings"));
-                    continue;
-                }
-            };
-
-            match s.parse::<Exemption>() {
-                Ok(exemption) => {
-                    vec.push(exemption);
-                }
-                Err(e) => {
-                    report.error(format!("Error in exemptions.{key}: {e}"));
-                    continue;
-                }
-            }
+        if divisions.iter().any(|d| d.name == *alias) {
+            report.error(format!("Alias '{alias}' is already the name of a division"));
+            continue;
+        }
+        if !divisions.iter().any(|d| d.name == target) {
+            report.error(format!(
+                "Alias '{alias}' refers to unknown division '{target}'"
+            ));
+            continue;
         }
 
-        acc.push(ExemptionConfig {
-            meet_folder: key.clone(),
-            exemptions: vec,
-        });
+        acc.insert(alias.clone(), target.to_string());
     }
 
     acc
 }
 
-fn parse_config(root: &Value, mut report: Report) -> Result<CheckResult, Box<dyn Error>> {
-    // The highest-level Value must be a table.
-    let table = match root.as_table() {
+/// Federation policy from the optional "metadata" table, used to lint the divisions.
+#[derive(Debug, Default)]
+struct Metadata {
+    /// (Sex, Equipment) pairs that the federation doesn't support.
+    unsupported: Vec<(Sex, Equipment)>,
+    /// The oldest age allowed in youth divisions.
+    youth_cutoff: Option<Age>,
+    /// The federation whose weightclasses this one inherits.
+    parent_federation: Option<Federation>,
+    /// When fourth attempts are allowed.
+    fourth_attempts: FourthAttemptPolicy,
+    /// When the federation was founded.
+    founded: Option<Date>,
+}
+
+/// Parses the optional "metadata" table.
+///
+/// Federation rulebooks sometimes disallow equipment for one sex, written as
+/// `unsupported = [{ sex = "F", equipment = ["Multi-ply"] }]`, or limit youth
+/// divisions to an age, written as `youth_cutoff = 14`. Affiliates that use
+/// their parent's weightclasses name it, like `parent_federation = "IPF"`.
+/// Fourth attempts may be limited with `allow_fourth_attempts = "WROnly"`.
+/// The founding date, written as `founded = "1981-06-01"`, dates the earliest weightclasses.
+fn parse_metadata(value: &Value, report: &mut Report) -> Metadata {
+    let mut acc = Metadata::default();
+
+    let table = match value.as_table() {
         Some(t) => t,
         None => {
-            report.error("Root value must be a Table");
-            return Ok(CheckResult {
-                report,
-                config: None,
-            });
+            report.error("Section 'metadata' must be a Table");
+            return acc;
         }
     };
 
-    // Parse the "options" table.
-    let options = table
-        .get("options")
-        .and_then(|v| parse_options(v, &mut report));
+    if let Some(v) = table.get("youth_cutoff") {
+        match v.as_integer().and_then(|n| u8::try_from(n).ok()) {
+            Some(age) => acc.youth_cutoff = Some(Age::Exact(age)),
+            None => report.error("Value 'metadata.youth_cutoff' must be an age, like 14"),
+        }
+    }
 
-    // Parse the "divisions" table.
-    let divisions = match table.get("divisions") {
-        Some(v) => parse_divisions(v, &mut report),
-        None => {
-            report.error("Missing the 'divisions' table");
-            return Ok(CheckResult {
-                report,
-                config: None,
-            });
+    if let Some(v) = table.get("parent_federation") {
+        match v.as_str() {
+            Some(name) => match name.parse::<Federation>() {
+                Ok(federation) => acc.parent_federation = Some(federation),
+                Err(_) => report.error(format!(
+                    "Unknown federation '{name}' in metadata.parent_federation"
+                )),
+            },
+            None => report.error("Value 'metadata.parent_federation' must be a String"),
         }
-    };
+    }
 
-    // Parse the "weightclasses" table.
-    let weightclasses = match table.get("weightclasses") {
-        Some(v) => parse_weightclasses(v, &divisions, &mut report),
-        None => {
-            report.error("Missing the 'weightclasses' table");
-            return Ok(CheckResult {
-                report,
-                config: None,
-            });
+    if let Some(v) = table.get("founded") {
+        match v.clone().try_into::<Date>() {
+            Ok(date) if date.is_valid() => acc.founded = Some(date),
+            _ => report.error("Value 'metadata.founded' must be a date, like \"1981-06-01\""),
         }
-    };
+    }
 
-    // Parse the optional "rulesets" table.
-    let rulesets = match table.get("rulesets") {
-        Some(v) => parse_rulesets(v, &mut report),
-        None => vec![],
+    if let Some(v) = table.get("allow_fourth_attempts") {
+        match v.as_str().and_then(|s| s.parse::<FourthAttemptPolicy>().ok()) {
+            Some(policy) => acc.fourth_attempts = policy,
+            None => report.error(
+                "Value 'metadata.allow_fourth_attempts' must be \"Always\", \"WROnly\", or \"Never\"",
+            ),
+        }
+    }
+
+    let unsupported = match table.get("unsupported") {
+        Some(v) => match v.as_array() {
+            Some(a) => a,
+            None => {
+                report.error("Value 'metadata.unsupported' must be an Array");
+                return acc;
+            }
+        },
+        None => return acc,
     };
 
-    // Parse the "exemptions" table.
-    let exemptions = match table.get("exemptions") {
-        Some(v) => parse_exemptions(v, &mut report),
+    for item in unsupported {
+        let sex = match item.get("sex").and_then(Value::as_str) {
+            Some(s) => match s.parse::<Sex>() {
+                Ok(sex) => sex,
+                Err(e) => {
+                    report.error(format!("Error in metadata.unsupported.sex: {e}"));
+                    continue;
+                }
+            },
+            None => {
+                report.error("Each of 'metadata.unsupported' needs a 'sex' String");
+                continue;
+            }
+        };
+        let equipment = match item.get("equipment") {
+            Some(v) => parse_equipment_list("metadata.unsupported", v, report),
+            None => {
+                report.error("Each of 'metadata.unsupported' needs an 'equipment' list");
+                continue;
+            }
+        };
+        for equipment in equipment.into_iter().flatten() {
+            acc.unsupported.push((sex, equipment));
+        }
+    }
+
+    acc
+}
+
+/// Warns about divisions whose sex and equipment the federation doesn't support.
+///
+/// Only divisions restricting both sex and equipment are checked.
+fn check_unsupported_divisions(
+    divisions: &[DivisionConfig],
+    unsupported: &[(Sex, Equipment)],
+    report: &mut Report,
+) {
+    for division in divisions {
+        let (sex, equipment) = match (division.sex, &division.equipment) {
+            (Some(sex), Some(equipment)) => (sex, equipment),
+            _ => continue,
+        };
+        for &e in equipment {
+            if unsupported.contains(&(sex, e)) {
+                report.warning(format!(
+                    "Division '{}' allows {e} for sex '{sex}', \
+                     but metadata.unsupported says the federation doesn't",
+                    division.name
+                ));
+            }
+        }
+    }
+}
+
+/// Whether a division's name says that it's for youth lifters, like "Sub-Juniors".
+fn is_youth_division_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase().replace([' ', '-'], "");
+    name.contains("youth") || name.contains("subjunior")
+}
+
+/// Warns about youth divisions that admit lifters older than the federation's cutoff.
+fn check_youth_cutoff(divisions: &[DivisionConfig], cutoff: Age, report: &mut Report) {
+    for division in divisions {
+        // Divisions limited only by birth date have no max age to compare.
+        let has_max = division.max.is_some() || !division.has_birth_date_bounds();
+        if is_youth_division_name(&division.name) && has_max && division.max > cutoff {
+            report.warning(format!(
+                "Division '{}' has a max age of {}, \
+                 but metadata.youth_cutoff limits youth divisions to {cutoff}",
+                division.name, division.max
+            ));
+        }
+    }
+}
+
+/// Warns about weightclass date ranges starting before the federation was founded.
+fn check_founding_date(weightclasses: &[WeightClassConfig], founded: Date, report: &mut Report) {
+    for wc in weightclasses {
+        let key = &wc.name;
+        for &(date_min, _) in wc.date_ranges.iter().filter(|&&(min, _)| min < founded) {
+            report.warning(format!(
+                "'{key}.date_range' starts on {date_min}, \
+                 but metadata.founded says the federation was founded on {founded}"
+            ));
+        }
+    }
+}
+
+/// Lowercase words in a division name that show it's for the given Equipment.
+fn equipment_name_tokens(equipment: Equipment) -> &'static [&'static str] {
+    match equipment {
+        Equipment::Raw => &["raw", "classic", "r"],
+        Equipment::Wraps => &["wraps", "w"],
+        Equipment::Single => &["single", "sp", "equipped", "eq"],
+        Equipment::Multi => &["multi", "mp", "equipped", "eq"],
+        Equipment::Unlimited => &["unlimited", "unl"],
+        Equipment::Straps => &["straps"],
+    }
+}
+
+/// Describes each division that restricts equipment without saying so in its name.
+///
+/// A Raw-only division named just "Open" is easily confused with an unrestricted
+/// "Open" on rankings pages. Names are split into words on spaces and dashes,
+/// which may be abbreviations like the "R" in "F-R", and naming any one of
+/// the allowed equipment is enough.
+///
+/// Federations abbreviate inconsistently, so this is only run as a heuristic.
+fn division_equipment_name_warnings(divisions: &[DivisionConfig]) -> Vec<String> {
+    let mut acc = vec![];
+    for division in divisions {
+        let Some(equipment) = &division.equipment else {
+            continue;
+        };
+        let name = division.name.to_ascii_lowercase();
+        let words: Vec<&str> = name.split([' ', '-']).collect();
+        let named = equipment
+            .iter()
+            .any(|&e| equipment_name_tokens(e).iter().any(|t| words.contains(t)));
+
+        if let (false, Some(first)) = (named, equipment.first()) {
+            acc.push(format!(
+                "Division '{}' only allows {}, but its name doesn't say so: \
+                 consider a name like '{first} {}'",
+                division.name,
+                division.equipment_list_string(),
+                division.name
+            ));
+        }
+    }
+    acc
+}
+
+/// Suggests an ASCII spelling for a division name with non-ASCII characters.
+///
+/// Returns `None` if the name is already ASCII, including ordinary punctuation
+/// like "U/18" or "Masters (40-49)", or if it has no ASCII spelling, like
+/// a name written in Cyrillic.
+fn suggest_division_name(name: &str) -> Option<String> {
+    if name.is_ascii() {
+        return None;
+    }
+
+    // Decomposing separates accents from their letters, and turns
+    // compatibility characters like full-width letters into plain ones.
+    let suggestion: String = name
+        .nfkd()
+        .filter(|&c| !unicode_normalization::char::is_combining_mark(c))
+        .map(|c| match c {
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+            '\u{2018}' | '\u{2019}' => '\'',
+            '\u{201C}' | '\u{201D}' => '"',
+            _ => c,
+        })
+        .collect();
+    (suggestion.is_ascii() && !suggestion.trim().is_empty()).then_some(suggestion)
+}
+
+/// Parses an `equipment` restriction, which may be a single Equipment or an Array.
+fn parse_equipment_list(key: &str, v: &Value, report: &mut Report) -> Option<Vec<Equipment>> {
+    if let Some(array) = v.as_array() {
+        if array.is_empty() {
+            report.error(format!("{key}.equipment cannot be empty"));
+        }
+
+        let mut vec = Vec::with_capacity(array.len());
+        for value in array {
+            match value.clone().try_into::<Equipment>() {
+                Ok(equipment) => {
+                    vec.push(equipment);
+                }
+                Err(e) => {
+                    report.error(format!("Error in {key}.equipment: {e}"));
+                }
+            }
+        }
+        Some(vec)
+    } else if let Some(s) = v.as_str() {
+        match s.parse::<Equipment>() {
+            Ok(equipment) => Some(vec![equipment]),
+            Err(e) => {
+                report.error(format!("Error in {key}.equipment: {e}"));
+                None
+            }
+        }
+    } else {
+        report.error(format!("{key}.equipment must be a sting or array"));
+        None
+    }
+}
+
+/// Parses an optional birth-date cutoff of a division, like `born_before = "2006-01-01"`.
+///
+/// An invalid date is reported, returning `Err`.
+fn parse_birth_date_cutoff(
+    key: &str,
+    field: &str,
+    division: &Value,
+    report: &mut Report,
+) -> Result<Option<Date>, ()> {
+    let Some(v) = division.get(field) else {
+        return Ok(None);
+    };
+    match v.as_str().and_then(|s| s.parse::<Date>().ok()) {
+        Some(date) if date.is_valid() => Ok(Some(date)),
+        Some(date) => {
+            report.error(format!(
+                "Value '{key}.{field}' is {date}, which does not exist in the Gregorian calendar"
+            ));
+            Err(())
+        }
         None => {
-            report.error("Missing the 'exemptions' table");
-            return Ok(CheckResult {
-                report,
-                config: None,
-            });
+            report.error(format!(
+                "Value '{key}.{field}' must be a Date, like '2006-01-01'"
+            ));
+            Err(())
+        }
+    }
+}
+
+/// Parses the `[divisions]` section.
+///
+/// Each division may be written as an inline table, like
+/// `open = { name = "Open", min = 0, max = 999 }`, or as a standard
+/// `[divisions.open]` table: both parse to the same `Value::Table`.
+fn parse_divisions(value: &Value, report: &mut Report) -> Vec<DivisionConfig> {
+    let mut acc = vec![];
+
+    let table = match value.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Section 'divisions' must be a Table");
+            return acc;
         }
     };
 
-    // Detect unknown sections.
-    for key in table.keys() {
-        match key.as_str() {
-            "options" | "divisions" | "exemptions" | "rulesets" | "weightclasses" => (),
+    for (key, division) in table {
+        // Parse the division name.
+        let name: &str = match division.get("name").and_then(Value::as_str) {
+            Some(s) => s,
+            None => {
+                report.error(format!("Value '{key}.name' must be a String"));
+                continue;
+            }
+        };
+
+        // Ensure that the Division name is unique.
+        for already_seen in &acc {
+            if already_seen.name == name {
+                report.error(format!("Division name '{name}' must be unique"));
+                break;
+            }
+        }
+
+        // Pasted names often contain accents, smart quotes, or fancy dashes.
+        if let Some(suggestion) = suggest_division_name(name) {
+            report.warning(format!(
+                "Division name '{name}' contains unusual characters: did you mean '{suggestion}'?"
+            ));
+        }
+
+        // Youth divisions may use birth-date cutoffs instead of, or as well as, ages.
+        let Ok(born_after) = parse_birth_date_cutoff(key, "born_after", division, report) else {
+            continue;
+        };
+        let Ok(born_before) = parse_birth_date_cutoff(key, "born_before", division, report) else {
+            continue;
+        };
+        let has_birth_dates = born_after.is_some() || born_before.is_some();
+        if let Some(error) = birth_date_cutoff_error(key, born_after, born_before) {
+            report.error(error);
+            continue;
+        }
+
+        // Parse the minimum age.
+        // The keyword "none" may be used for divisions without a minimum.
+        let min_age = match division.get("min") {
+            Some(Value::String(s)) if s == "none" => Age::unbounded_min(),
+            Some(v) if is_negative_number(v) => {
+                report.error(format!("Division '{key}' has a negative min age '{v}'"));
+                continue;
+            }
+            Some(v) => match v.clone().try_into::<Age>() {
+                Ok(age) => age,
+                Err(e) => {
+                    report.error(format!("Failed parsing {key}.min: {e}"));
+                    continue;
+                }
+            },
+            None if has_birth_dates => Age::None,
+            None => {
+                report.error(format!("Division '{key}' is missing the property 'min'"));
+                continue;
+            }
+        };
+
+        // Parse the maximum age.
+        // The keywords "max" and "none" may be used instead of 999.
+        let max_age = match division.get("max") {
+            Some(Value::String(s)) if s == "max" || s == "none" => Age::unbounded_max(),
+            Some(v) if is_negative_number(v) => {
+                report.error(format!("Division '{key}' has a negative max age '{v}'"));
+                continue;
+            }
+            Some(v) => match v.clone().try_into::<Age>() {
+                Ok(age) => age,
+                Err(e) => {
+                    report.error(format!("Failed parsing {key}.max: {e}"));
+                    continue;
+                }
+            },
+            None if has_birth_dates => Age::None,
+            None => {
+                report.error(format!("Division '{key}' is missing the property 'max'"));
+                continue;
+            }
+        };
+
+        // Open divisions must be specified as "min = 0" and "max = 999" (or "max").
+        match (min_age, max_age) {
+            (Age::None, Age::None) if has_birth_dates => (),
+            (Age::None, Age::None) => {
+                report.error(format!(
+                    "Division '{key}' has no age range: use 'min = 0' and 'max = 999'"
+                ));
+                continue;
+            }
+            (Age::None, _) | (_, Age::None) => {
+                report.warning(format!(
+                    "Division '{key}' has a half-open age range '{min_age}-{max_age}'"
+                ));
+            }
             _ => {
-                report.error(format!("Unknown section '{key}'"));
+                // The age range must be nonmonotonically increasing.
+                if min_age > max_age {
+                    report.error(format!(
+                        "Division '{key}' has an invalid age range '{min_age}-{max_age}'"
+                    ));
+                    continue;
+                }
             }
         }
-    }
 
-    Ok(CheckResult {
-        report,
-        config: Some(Config {
-            options,
-            divisions,
-            weightclasses,
-            exemptions,
-            rulesets,
-        }),
-    })
-}
+        // An optional sex restriction may be provided.
+        let sex: Option<Sex> = match division.get("sex") {
+            Some(v) => match v.clone().try_into::<Sex>() {
+                Ok(sex) => Some(sex),
+                Err(e) => {
+                    report.error(format!("Failed parsing {key}.sex: {e}"));
+                    None
+                }
+            },
+            None => None,
+        };
 
-/// Main entry point to CONFIG.toml testing.
-pub fn check_config(config: PathBuf) -> Result<CheckResult, Box<dyn Error>> {
-    let report = Report::new(config);
+        // An optional list of allowed equipment may be provided.
+        let equipment = division
+            .get("equipment")
+            .and_then(|v| parse_equipment_list(key, v, report));
 
-    let mut file = File::open(&report.path)?;
-    let mut config_str = String::new();
-    file.read_to_string(&mut config_str)?;
+        // Provides a Tested flag which sets some divisions as default-Tested.
+        let tested: Option<bool> = match division.get("tested").and_then(Value::as_str) {
+            Some(v) => match v {
+                "Yes" => Some(true),
+                "No" => Some(false),
+                _ => {
+                    report.error(format!("Failed parsing {key}.tested: invalid '{v}'"));
+                    None
+                }
+            },
+            None => None,
+        };
 
-    // Parse the entire string into TOML Value types.
-    let root = config_str.parse::<Value>()?;
-    parse_config(&root, report)
+        // Marks divisions that exist only for records purposes.
+        let records_only: Option<bool> = match division.get("records_only").and_then(Value::as_str)
+        {
+            Some(v) => match v {
+                "Yes" => Some(true),
+                "No" => Some(false),
+                _ => {
+                    report.error(format!("Failed parsing {key}.records_only: invalid '{v}'"));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // Marks the catch-all division, checked for uniqueness below.
+        let fallback: bool = match division.get("fallback") {
+            Some(v) => match v.as_bool() {
+                Some(b) => b,
+                None => {
+                    report.error(format!("Value '{key}.fallback' must be a Boolean"));
+                    false
+                }
+            },
+            None => false,
+        };
+
+        // Birth-year cohorts may be used instead of the age on the meet date.
+        let age_mode: AgeMode = match division.get("age_mode") {
+            Some(v) => match v.as_str().and_then(|s| s.parse::<AgeMode>().ok()) {
+                Some(mode) => mode,
+                None => {
+                    report.error(format!(
+                        "Value '{key}.age_mode' must be \"age\" or \"birthyear\""
+                    ));
+                    AgeMode::default()
+                }
+            },
+            None => AgeMode::default(),
+        };
+
+        // Position in the display order, checked for uniqueness below.
+        let sort_order: Option<u32> = match division.get("sort_order") {
+            Some(v) => match v.as_integer().and_then(|n| u32::try_from(n).ok()) {
+                Some(n) => Some(n),
+                None => {
+                    report.error(format!(
+                        "Value '{key}.sort_order' must be a non-negative Integer"
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // The competition level is metadata for filtering results downstream.
+        let level: Option<CompetitionLevel> = match division.get("level") {
+            Some(v) => match v.as_str().and_then(|s| s.parse::<CompetitionLevel>().ok()) {
+                Some(level) => Some(level),
+                None => {
+                    report.error(format!(
+                        "Value '{key}.level' must be \"Local\", \"Regional\", \"National\", \
+                         or \"International\""
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // Provides a Place value that all entries in the Division must have.
+        // This is used to enforce Guest divisions being marked Guest.
+        let place: Option<Place> = match division.get("place").and_then(Value::as_str) {
+            Some(s) => match s.parse::<Place>() {
+                Ok(p) => Some(p),
+                Err(e) => {
+                    report.error(format!("Failed parsing {key}.place: {e}"));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        acc.push(DivisionConfig {
+            name: name.to_string(),
+            min: min_age,
+            max: max_age,
+            sex,
+            equipment,
+            tested,
+            place,
+            records_only,
+            fallback,
+            age_mode,
+            sort_order,
+            level,
+            born_after,
+            born_before,
+        });
+    }
+
+    // No two divisions may share a position in the display order.
+    for (i, division) in acc.iter().enumerate() {
+        let Some(order) = division.sort_order else {
+            continue;
+        };
+        if let Some(other) = acc[..i].iter().find(|d| d.sort_order == Some(order)) {
+            report.error(format!(
+                "Divisions '{}' and '{}' have the same sort_order {order}",
+                other.name, division.name
+            ));
+        }
+    }
+
+    // Each sex may have at most one fallback division.
+    for sex in [Sex::M, Sex::F, Sex::Mx] {
+        let fallbacks: Vec<&str> = acc
+            .iter()
+            .filter(|d| d.fallback && d.sex.is_none_or(|s| s == sex))
+            .map(|d| d.name.as_str())
+            .collect();
+        if fallbacks.len() > 1 {
+            report.error(format!(
+                "Sex '{sex}' has multiple fallback divisions: {}",
+                fallbacks.join(", ")
+            ));
+        }
+    }
+
+    acc
+}
+
+/// Weightclass date ranges starting before this date are typos, since
+/// organized powerlifting competition did not exist yet.
+///
+/// Ranges starting after this but before the federation was founded are
+/// caught by `metadata.founded`, if it is given.
+const WEIGHTCLASS_DATE_FLOOR: Date = Date::from_parts(1950, 01, 01);
+
+/// Whether a TOML value is a negative number, or a String holding one.
+///
+/// `Age` can't represent negative ages, so these would otherwise only produce
+/// an unhelpful integer parsing error.
+fn is_negative_number(v: &Value) -> bool {
+    match v {
+        Value::Integer(n) => *n < 0,
+        Value::Float(f) => *f < 0.0,
+        Value::String(s) => s.trim().parse::<f64>().is_ok_and(|f| f < 0.0),
+        _ => false,
+    }
+}
+
+/// Parses one member of a `classes` array, returning the class and whether it is provisional.
+///
+/// Classes are usually Strings like "90" or "90+", but may also be a table
+/// like `{ kg = 90, provisional = true }` for classes that aren't yet official.
+fn parse_class(key: &str, value: &Value, report: &mut Report) -> Option<(WeightClassKg, bool)> {
+    let (kg, provisional) = match value.as_table() {
+        Some(t) => {
+            let kg = match t.get("kg") {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Integer(n)) => n.to_string(),
+                Some(Value::Float(f)) => f.to_string(),
+                _ => {
+                    report.error(format!(
+                        "Tables in '{key}.classes' need a 'kg' Number or String"
+                    ));
+                    return None;
+                }
+            };
+            let provisional = match t.get("provisional") {
+                Some(v) => match v.as_bool() {
+                    Some(b) => b,
+                    None => {
+                        report.error(format!("Value '{key}.classes.provisional' must be a Bool"));
+                        return None;
+                    }
+                },
+                None => false,
+            };
+            (kg, provisional)
+        }
+        None => match value.as_str() {
+            Some(s) => (s.to_string(), false),
+            None => {
+                report.error(format!(
+                    "Error in '{key}.classes': '{value}' must be a String"
+                ));
+                return None;
+            }
+        },
+    };
+
+    match kg.parse::<WeightClassKg>() {
+        Ok(class) => Some((class, provisional)),
+        Err(e) => {
+            report.error(format!("Error in '{key}.classes': {e}"));
+            None
+        }
+    }
+}
+
+/// Parses one `[min, max]` pair of a weightclass set's `date_range`.
+fn parse_weightclass_date_range(
+    key: &str,
+    pair: &Value,
+    report: &mut Report,
+) -> Option<(Date, Date)> {
+    let array = match pair.as_array() {
+        Some(array) if array.len() == 2 => array,
+        _ => {
+            report.error(format!("Array '{key}.date_range' must have 2 items"));
+            return None;
+        }
+    };
+
+    let mut dates = array.iter().map(|v| v.clone().try_into::<Date>());
+    let (date_min, date_max) = match (dates.next()?, dates.next()?) {
+        (Ok(min), Ok(max)) => (min, max),
+        (Err(e), _) | (_, Err(e)) => {
+            report.error(format!("Error in '{key}.date_range': {e}"));
+            return None;
+        }
+    };
+
+    for date in [date_min, date_max] {
+        if !date.is_valid() {
+            report.error(format!(
+                "'{key}.date_range' contains {date}, which does not exist in the Gregorian calendar"
+            ));
+            return None;
+        }
+    }
+
+    if date_min < WEIGHTCLASS_DATE_FLOOR {
+        report.error(format!(
+            "'{key}.date_range' starts on {date_min}, before {WEIGHTCLASS_DATE_FLOOR}"
+        ));
+        return None;
+    }
+    Some((date_min, date_max))
+}
+
+fn parse_weightclasses(
+    value: &Value,
+    divisions: &[DivisionConfig],
+    report: &mut Report,
+) -> Vec<WeightClassConfig> {
+    let mut acc = vec![];
+
+    let table = match value.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Section 'weightclasses' must be a Table");
+            return acc;
+        }
+    };
+
+    for (key, weightclass) in table {
+        // Parse the list of weightclasses.
+        let mut provisional = vec![];
+        let classes = match weightclass.get("classes").and_then(Value::as_array) {
+            Some(array) => {
+                let mut vec = Vec::with_capacity(array.len());
+                for value in array {
+                    if let Some((class, is_provisional)) = parse_class(key, value, report) {
+                        if is_provisional {
+                            provisional.push(class);
+                        }
+                        vec.push(class);
+                    }
+                }
+                vec
+            }
+            None => {
+                report.error(format!("Value '{key}.classes' must be an Array"));
+                continue;
+            }
+        };
+
+        // Parse the date ranges: either one `[min, max]` pair or an array of pairs.
+        let pairs: Vec<&Value> = match weightclass.get("date_range") {
+            Some(Value::Array(array)) if array.first().is_some_and(Value::is_array) => {
+                array.iter().collect()
+            }
+            Some(pair @ Value::Array(_)) => vec![pair],
+            _ => {
+                report.error(format!("Value '{key}.date_range' must be an Array"));
+                continue;
+            }
+        };
+        let ranges: Vec<Option<(Date, Date)>> = pairs
+            .into_iter()
+            .map(|pair| parse_weightclass_date_range(key, pair, report))
+            .collect();
+        let Some(mut date_ranges) = ranges.into_iter().collect::<Option<Vec<_>>>() else {
+            continue;
+        };
+        date_ranges.sort();
+        if let Some(w) = date_ranges.windows(2).find(|w| w[1].0 <= w[0].1) {
+            report.error(format!(
+                "'{key}.date_range' has overlapping ranges ending on {} and starting on {}",
+                w[0].1, w[1].0
+            ));
+            continue;
+        }
+
+        // Parse the sex restriction.
+        let sex = match weightclass.get("sex").and_then(Value::as_str) {
+            Some(s) => match s.parse::<Sex>() {
+                Ok(sex) => sex,
+                Err(e) => {
+                    report.error(format!("Error in '{key}.sex': {e}"));
+                    continue;
+                }
+            },
+            None => {
+                report.error(format!("Value '{key}.sex' must be a String"));
+                continue;
+            }
+        };
+
+        // Parse the optional division restriction.
+        let divindices: Option<Vec<usize>> = match weightclass.get("divisions") {
+            Some(v) => match v.as_array() {
+                Some(a) => {
+                    let mut vec = Vec::with_capacity(a.len());
+                    for div in a {
+                        match div.as_str() {
+                            Some(div) => match divisions.iter().position(|r| r.name == div) {
+                                Some(idx) => vec.push(idx),
+                                None => {
+                                    report.error(format!(
+                                        "Invalid division '{div}' in {key}.divisions"
+                                    ));
+                                    continue;
+                                }
+                            },
+                            None => {
+                                report.error(format!(
+                                    "Array '{key}.divisions' may only contain Strings"
+                                ));
+                                continue;
+                            }
+                        }
+                    }
+                    Some(vec)
+                }
+                None => {
+                    report.error(format!("Value '{key}.divisions' must be an Array"));
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        // Parse the optional equipment restriction.
+        let equipment = weightclass
+            .get("equipment")
+            .and_then(|v| parse_equipment_list(key, v, report));
+
+        // Parse the optional source of the classes.
+        let source = match weightclass.get("source") {
+            Some(v) => match v.as_str() {
+                Some(s) if !s.trim().is_empty() => Some(s.to_string()),
+                _ => {
+                    report.error(format!("Value '{key}.source' must be a non-empty String"));
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        // The classes must be ordered from least to greatest.
+        // This ordering is required for the logic in check_weightclass_consistency.
+        for error in weightclass_ordering_errors(key, &classes) {
+            report.error(error);
+        }
+
+        acc.push(WeightClassConfig {
+            name: key.to_string(),
+            classes,
+            date_ranges,
+            sex,
+            divisions: divindices,
+            equipment,
+            provisional,
+            source,
+        });
+    }
+
+    acc
+}
+
+fn parse_rulesets(value: &Value, report: &mut Report) -> Vec<RuleSetConfig> {
+    let mut acc = vec![];
+
+    let table = match value.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Section 'rulesets' must be a Table");
+            return acc;
+        }
+    };
+
+    for (key, section) in table {
+        // Parse the list of rulesets.
+        let ruleset = match section.get("ruleset").and_then(Value::as_array) {
+            Some(array) => {
+                let mut ruleset = RuleSet::default();
+                for value in array {
+                    match value.clone().try_into::<Rule>() {
+                        Ok(rule) => {
+                            ruleset.add(rule);
+                        }
+                        Err(e) => {
+                            report.error(format!("Error in '{key}.ruleset': {e}"));
+                        }
+                    }
+                }
+                ruleset
+            }
+            None => {
+                report.error(format!("Value '{key}.ruleset' must be an Array"));
+                continue;
+            }
+        };
+
+        // Parse the min and max dates.
+        let date_range = match section.get("date_range").and_then(Value::as_array) {
+            Some(array) => {
+                if array.len() != 2 {
+                    report.error(format!("Array '{key}.date_range' must have 2 items"));
+                    continue;
+                }
+                // TODO: These clone() calls can be removed by using Value::as_str().
+                let date_min = match array[0].clone().try_into::<Date>() {
+                    Ok(date) => date,
+                    Err(e) => {
+                        report.error(format!("Error in '{key}.date_range': {e}"));
+                        continue;
+                    }
+                };
+                let date_max = match array[1].clone().try_into::<Date>() {
+                    Ok(date) => date,
+                    Err(e) => {
+                        report.error(format!("Error in '{key}.date_range': {e}"));
+                        continue;
+                    }
+                };
+                (date_min, date_max)
+            }
+            None => {
+                report.error(format!("Value '{key}.date_range' must be an Array"));
+                continue;
+            }
+        };
+
+        acc.push(RuleSetConfig {
+            ruleset,
+            date_min: date_range.0,
+            date_max: date_range.1,
+        });
+    }
+
+    acc
+}
+
+fn parse_scoring(value: &Value, report: &mut Report) -> Option<ScoringConfig> {
+    let table = match value.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Section 'scoring' must be a Table");
+            return None;
+        }
+    };
+
+    let formula = match table.get("formula").and_then(Value::as_str) {
+        Some(s) => s,
+        None => {
+            report.error("Value 'scoring.formula' must be a String");
+            return None;
+        }
+    };
+    if formula.parse::<PointsSystem>().is_err() {
+        report.error(format!("Unknown scoring formula '{formula}'"));
+        return None;
+    }
+
+    let mut params = HashMap::new();
+    if let Some(v) = table.get("params") {
+        match v.as_table() {
+            Some(t) => {
+                for (key, value) in t {
+                    // Allow integers, like "offset = 500".
+                    let n = value
+                        .as_float()
+                        .or_else(|| value.as_integer().map(|i| i as f64));
+                    match n {
+                        Some(n) => {
+                            params.insert(key.clone(), n);
+                        }
+                        None => {
+                            report.error(format!("Value 'scoring.params.{key}' must be a number"));
+                        }
+                    }
+                }
+            }
+            None => {
+                report.error("Value 'scoring.params' must be a Table");
+            }
+        }
+    }
+
+    Some(ScoringConfig {
+        formula: formula.to_string(),
+        params,
+    })
+}
+
+fn parse_exemptions(value: &Value, report: &mut Report) -> Vec<ExemptionConfig> {
+    let mut acc = vec![];
+
+    let table = match value.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Section 'exemptions' must be a Table");
+            return acc;
+        }
+    };
+
+    for (key, exemptions) in table {
+        // Keys are matched exactly against meet folder names.
+        if key.trim().is_empty() {
+            report.error(format!("Exemption folder '{key}' must not be empty"));
+            continue;
+        }
+        if key.contains(['/', '\\']) {
+            report.warning(format!(
+                "Exemption folder '{key}' contains a path separator, \
+                 but only the meet folder's own name is matched"
+            ));
+        }
+
+        let exemptions = match exemptions.as_array() {
+            Some(a) => a,
+            None => {
+                report.error(format!("exemptions.{key} must be an Array"));
+                continue;
+            }
+        };
+
+        let mut vec = Vec::with_capacity(exemptions.len());
+        for exemption in exemptions {
+            let s = match exemption.as_str() {
+                Some(s) => s,
+                None => {
+                    report.error(format!("exemptions.{key} must contain Strings"));
+                    continue;
+                }
+            };
+
+            match s.parse::<Exemption>() {
+                Ok(exemption) => {
+                    vec.push(exemption);
+                }
+                Err(e) => {
+                    match nearest_exemption(s) {
+                        Some(near) => report.error(format!(
+                            "Error in exemptions.{key}: {e}: did you mean '{near}'?"
+                        )),
+                        None => report.error(format!("Error in exemptions.{key}: {e}")),
+                    }
+                    continue;
+                }
+            }
+        }
+
+        let exemption = ExemptionConfig {
+            meet_folder: key.clone(),
+            exemptions: vec,
+        };
+        if !exemption.is_valid() {
+            report.warning(format!(
+                "exemptions.{key} lists no exemptions, so has no effect"
+            ));
+        }
+
+        let deduplicated = exemption.deduplicated();
+        if deduplicated.exemptions.len() < exemption.exemptions.len() {
+            report.warning(format!(
+                "exemptions.{key} lists an exemption more than once"
+            ));
+        }
+        acc.push(deduplicated);
+    }
+
+    acc
+}
+
+/// Warns about exemptions that cannot have any effect given the rest of the config.
+fn check_exemption_applicability(
+    divisions: &[DivisionConfig],
+    exemptions: &[ExemptionConfig],
+    report: &mut Report,
+) {
+    // Configs without divisions already skip all division-based checks,
+    // including the weightclass checks.
+    //
+    // Note that a config with divisions but no weightclasses still benefits from
+    // ExemptWeightClassConsistency, since no entry could match a weightclass group.
+    if !divisions.is_empty() {
+        return;
+    }
+
+    for ec in exemptions {
+        for exemption in &ec.exemptions {
+            if let Exemption::ExemptDivision | Exemption::ExemptWeightClassConsistency = exemption {
+                report.warning(format!(
+                    "Exemption '{exemption:?}' for meet '{}' has no effect: \
+                     no divisions are configured",
+                    ec.meet_folder
+                ));
+            }
+        }
+    }
+}
+
+/// A project-specific lint, run on each division after the standard checks.
+pub type DivisionLint<'a> = &'a mut dyn FnMut(&DivisionConfig, &mut Report);
+
+fn parse_config(
+    root: &Value,
+    mut report: Report,
+    opts: ParseOptions,
+    division_lint: DivisionLint,
+) -> Result<CheckResult, Box<dyn Error>> {
+    // The highest-level Value must be a table.
+    let table = match root.as_table() {
+        Some(t) => t,
+        None => {
+            report.error("Root value must be a Table");
+            return Ok(CheckResult {
+                report,
+                config: None,
+                elapsed: None,
+                federation: None,
+            });
+        }
+    };
+
+    // Parse the "options" table.
+    let options = table
+        .get("options")
+        .and_then(|v| parse_options(v, &mut report));
+
+    // Parse the "divisions" table.
+    let divisions = match table.get("divisions") {
+        Some(v) => parse_divisions(v, &mut report),
+        None if opts.allow_missing_sections => {
+            report.warning("Missing the 'divisions' table");
+            vec![]
+        }
+        None => {
+            report.error("Missing the 'divisions' table");
+            return Ok(CheckResult {
+                report,
+                config: None,
+                elapsed: None,
+                federation: None,
+            });
+        }
+    };
+    for division in &divisions {
+        division_lint(division, &mut report);
+    }
+
+    // Parse the "weightclasses" table.
+    let weightclasses = match table.get("weightclasses") {
+        Some(v) => parse_weightclasses(v, &divisions, &mut report),
+        None if opts.allow_missing_sections => {
+            report.warning("Missing the 'weightclasses' table");
+            vec![]
+        }
+        None => {
+            report.error("Missing the 'weightclasses' table");
+            return Ok(CheckResult {
+                report,
+                config: None,
+                elapsed: None,
+                federation: None,
+            });
+        }
+    };
+
+    // Parse the optional "rulesets" table.
+    let rulesets = match table.get("rulesets") {
+        Some(v) => parse_rulesets(v, &mut report),
+        None => vec![],
+    };
+
+    // Parse the "exemptions" table.
+    let exemptions = match table.get("exemptions") {
+        Some(v) => parse_exemptions(v, &mut report),
+        None if opts.allow_missing_sections => {
+            report.warning("Missing the 'exemptions' table");
+            vec![]
+        }
+        None => {
+            report.error("Missing the 'exemptions' table");
+            return Ok(CheckResult {
+                report,
+                config: None,
+                elapsed: None,
+                federation: None,
+            });
+        }
+    };
+
+    // Parse the optional "scoring" table.
+    let scoring = table
+        .get("scoring")
+        .and_then(|v| parse_scoring(v, &mut report));
+
+    // Parse the optional "aliases" table.
+    let aliases = table
+        .get("aliases")
+        .map(|v| parse_aliases(v, &divisions, &mut report))
+        .unwrap_or_default();
+
+    // Parse the optional "metadata" table.
+    let metadata = table
+        .get("metadata")
+        .map(|v| parse_metadata(v, &mut report))
+        .unwrap_or_default();
+    check_unsupported_divisions(&divisions, &metadata.unsupported, &mut report);
+    if let Some(cutoff) = metadata.youth_cutoff {
+        check_youth_cutoff(&divisions, cutoff, &mut report);
+    }
+    if let Some(founded) = metadata.founded {
+        check_founding_date(&weightclasses, founded, &mut report);
+    }
+
+    // Detect unknown sections.
+    for key in table.keys() {
+        match key.as_str() {
+            "aliases" | "options" | "divisions" | "exemptions" | "metadata" | "rulesets"
+            | "scoring" | "weightclasses" => (),
+            _ => {
+                report.error(format!("Unknown section '{key}'"));
+            }
+        }
+    }
+
+    let config = Config {
+        aliases,
+        parent_federation: metadata.parent_federation,
+        fourth_attempts: metadata.fourth_attempts,
+        ..Config::new(
+            options,
+            divisions,
+            weightclasses,
+            exemptions,
+            rulesets,
+            scoring,
+        )
+    };
+    config.validate(ValidateOptions::default(), &mut report);
+
+    Ok(CheckResult {
+        report,
+        config: Some(config),
+        elapsed: None,
+        federation: None,
+    })
+}
+
+/// The parts of an entry needed to check it against a Config.
+#[derive(Clone, Debug, Default)]
+pub struct EntryLite {
+    /// The meet folder relative to the CONFIG.toml, used to look up exemptions.
+    pub meet_folder: String,
+    pub age: Age,
+    pub sex: Sex,
+    pub equipment: Equipment,
+    pub tested: Option<bool>,
+    pub bodyweightkg: WeightKg,
+    pub date: Date,
+    /// Checked against birth-date cutoffs, if known.
+    pub birthdate: Option<Date>,
+}
+
+impl DivisionConfig {
+    /// Whether the division could contain the entry.
+    ///
+    /// Entries with an unknown age or testedness can't be ruled out by those.
+    fn admits(&self, entry: &EntryLite) -> bool {
+        self.sex.is_none_or(|s| s == entry.sex)
+            && (entry.age == Age::None || self.age_contains(self.effective_age(entry.age)))
+            && entry.birthdate.is_none_or(|b| self.birth_date_contains(b))
+            && self
+                .equipment
+                .as_ref()
+                .is_none_or(|e| e.contains(&entry.equipment))
+            && self
+                .tested
+                .is_none_or(|t| entry.tested.is_none_or(|e| e == t))
+    }
+}
+
+/// Checks that real entries each find a division and a weightclass in the Config.
+///
+/// This catches configurations that are internally valid but don't describe
+/// the federation's data. Exempted meets are skipped for the exempted checks.
+pub fn check_entries_against_config(
+    entries: impl Iterator<Item = EntryLite>,
+    config: &Config,
+) -> Report {
+    let mut report = Report::new(PathBuf::from("[entries]"));
+
+    for (i, entry) in entries.enumerate() {
+        let folder = entry.meet_folder.as_str();
+        let describe = || {
+            format!(
+                "Entry {} in '{folder}' ({}, age {}, {})",
+                i + 1,
+                entry.sex,
+                entry.age,
+                entry.equipment
+            )
+        };
+
+        let divisions: Vec<&DivisionConfig> = config
+            .divisions
+            .iter()
+            .filter(|d| d.admits(&entry))
+            .collect();
+        if divisions.is_empty() && !config.is_exempted(folder, Exemption::ExemptDivision) {
+            report.error(format!("{} matches no division", describe()));
+        }
+
+        if config.is_exempted(folder, Exemption::ExemptWeightClassConsistency) {
+            continue;
+        }
+
+        // Any division's weightclasses will do, including the general ones.
+        let names = divisions.iter().map(|d| Some(d.name.as_str()));
+        let has_class = names.chain(std::iter::once(None)).any(|name| {
+            config
+                .effective_weightclasses_for_entry(entry.sex, entry.equipment, entry.date, name)
+                .is_some_and(|wc| {
+                    entry.bodyweightkg.is_zero()
+                        || wc.class_for_bodyweight(entry.bodyweightkg).is_some()
+                })
+        });
+        if !has_class {
+            report.error(format!(
+                "{} with BodyweightKg {} on {} matches no weightclass",
+                describe(),
+                entry.bodyweightkg,
+                entry.date
+            ));
+        }
+    }
+
+    report
+}
+
+/// Counts the single-character edits needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Finds the Exemption most similar to a misspelled one, for suggesting a fix.
+///
+/// Like folder suggestions, only names differing in at most half their characters count.
+fn nearest_exemption(s: &str) -> Option<Exemption> {
+    Exemption::iter()
+        .map(|e| (edit_distance(s, &e.to_string()), e))
+        .filter(|&(distance, _)| distance <= s.chars().count() / 2)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, e)| e)
+}
+
+/// Checks that each exemption names a meet folder next to the CONFIG.toml.
+///
+/// Exemptions for missing folders are usually left behind when a meet is
+/// renamed, so the most similarly-named existing folder is suggested as a fix.
+/// Folders that exist but lack a meet's data files are warned about, since
+/// there is nothing there for the exemption to apply to.
+pub fn check_exemption_folders(config: &Config, config_dir: &Path, report: &mut Report) {
+    let mut folders: Vec<String> = match std::fs::read_dir(config_dir) {
+        Ok(dir) => dir
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect(),
+        Err(e) => {
+            report.error(format!("Failed reading '{}': {e}", config_dir.display()));
+            return;
+        }
+    };
+    folders.sort();
+
+    for ec in &config.exemptions {
+        let folder = &ec.meet_folder;
+        let path = config_dir.join(folder);
+        if path.is_dir() {
+            let missing: Vec<&str> = ["entries.csv", "meet.csv"]
+                .into_iter()
+                .filter(|file| !path.join(file).is_file())
+                .collect();
+            if !missing.is_empty() {
+                report.warning(format!(
+                    "Exemption folder '{folder}' is missing {}: the exemption has no effect",
+                    missing.join(" and ")
+                ));
+            }
+            continue;
+        }
+
+        // Only suggest folders that differ in at most half their characters.
+        let nearest = folders
+            .iter()
+            .map(|f| (edit_distance(folder, f), f))
+            .filter(|&(distance, _)| distance <= folder.chars().count() / 2)
+            .min();
+
+        match nearest {
+            Some((_, f)) => report.error(format!(
+                "Exemption folder '{folder}' does not exist: fix by renaming it to '{f}'"
+            )),
+            None => report.error(format!("Exemption folder '{folder}' does not exist")),
+        }
+    }
+}
+
+/// Collects documentation comments, which are full lines starting with `#:`.
+///
+/// The `toml` crate discards comments, so these are found in the raw text.
+/// Consecutive `#:` lines form a single block, joined by newlines.
+fn extract_doc_comments(s: &str) -> Vec<String> {
+    let mut acc = vec![];
+    let mut block: Vec<&str> = vec![];
+    for line in s.lines() {
+        match line.trim_start().strip_prefix("#:") {
+            Some(text) => block.push(text.trim()),
+            None if !block.is_empty() => {
+                acc.push(block.join("\n"));
+                block.clear();
+            }
+            None => (),
+        }
+    }
+    if !block.is_empty() {
+        acc.push(block.join("\n"));
+    }
+    acc
+}
+
+/// Suffix of the optional file, next to a CONFIG.toml, listing accepted warnings.
+const WARNING_BASELINE_SUFFIX: &str = "warnings-baseline.toml";
+
+/// Warnings that have been reviewed and accepted, so are no longer reported.
+///
+/// There are no stable warning codes, so warnings are matched by their full text.
+#[derive(Deserialize)]
+struct WarningBaseline {
+    #[serde(default)]
+    warnings: Vec<String>,
+}
+
+/// Returns the path of the warning baseline for a config, like `CONFIG.warnings-baseline.toml`.
+pub(crate) fn warning_baseline_path(config: &Path) -> PathBuf {
+    let stem = config
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("CONFIG");
+    config.with_file_name(format!("{stem}.{WARNING_BASELINE_SUFFIX}"))
+}
+
+/// Removes warnings listed in the config's baseline file, if it has one.
+///
+/// Errors are never suppressed. Baselined warnings that no longer occur are
+/// reported as info, so that the baseline can be pruned. This must run after
+/// every warning has been added, including optional ones like
+/// [`Config::heuristic_warnings`], so it is left to the caller.
+pub fn apply_warning_baseline(report: &mut Report) {
+    let path = warning_baseline_path(&report.path);
+    let baseline_str = match std::fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let baseline: WarningBaseline = match toml::from_str(&baseline_str) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            report.error(format!("Failed parsing '{}': {e}", path.display()));
+            return;
+        }
+    };
+
+    let mut used = vec![false; baseline.warnings.len()];
+    report.messages.retain(|message| match message {
+        Message::Warning(s) => match baseline.warnings.iter().position(|w| w == s) {
+            Some(i) => {
+                used[i] = true;
+                false
+            }
+            None => true,
+        },
+        _ => true,
+    });
+
+    for (warning, _) in baseline.warnings.iter().zip(used).filter(|(_, used)| !used) {
+        report.info(format!("Baselined warning no longer occurs: {warning}"));
+    }
+}
+
+/// Reports a TOML syntax error, rewording the most common mistakes.
+///
+/// The `toml` crate's messages are terse, like "duplicate key `open` in table `divisions`".
+fn report_toml_error(src: &str, e: &toml::de::Error, report: &mut Report) {
+    let offset = e.span().map_or(0, |span| span.start);
+    let (line, column) = crate::offset_to_line_col(src, offset);
+    let raw = e.message().replace('\n', ": ");
+
+    // The last line of the message is the most specific.
+    let detail = e.message().lines().last().unwrap_or_default();
+    // Misplaced commas are reported at the comma itself.
+    let at_comma = src.get(offset..).is_some_and(|s| s.starts_with(','));
+
+    let message = if let Some(rest) = detail.strip_prefix("duplicate key `") {
+        // Like "open` in table `divisions`" or "divisions` in document root".
+        let (key, location) = rest.split_once('`').unwrap_or((rest, ""));
+        if location == " in document root" {
+            format!("Section [{key}] is defined more than once")
+        } else if let Some(table) = location.strip_prefix(" in table `") {
+            let table = table.trim_end_matches('`');
+            format!("Key '{key}' is defined more than once in [{table}]")
+        } else {
+            format!("Key '{key}' is defined more than once")
+        }
+    } else if raw.starts_with("invalid inline table") && at_comma {
+        "Inline tables, in { braces }, can't have a trailing comma".to_string()
+    } else if raw.starts_with("invalid array") && at_comma {
+        "Arrays can't have an empty item between commas".to_string()
+    } else {
+        format!("TOML syntax error: {raw}")
+    };
+
+    report.error_on(line as u64, format!("Column {column}: {message}"));
+}
+
+/// Determines the federation from the folder containing a path, like `meet-data/ipf/CONFIG.toml`.
+///
+/// The nearest enclosing folder named for a federation is used, so paths
+/// within a meet folder also work. Folders like `meet-data/mags` hold
+/// results from many federations, so have none.
+pub fn federation_from_path(path: &Path) -> Option<Federation> {
+    path.ancestors()
+        .skip(1)
+        .filter_map(|dir| dir.file_name()?.to_str())
+        .find_map(|name| name.parse::<Federation>().ok())
+}
+
+/// Main entry point to CONFIG.toml testing.
+///
+/// If `timed` is set, the time taken to check the file is recorded
+/// in the `elapsed` field of the result.
+pub fn check_config(config: PathBuf, timed: bool) -> Result<CheckResult, Box<dyn Error>> {
+    check_config_with(config, timed, &mut |_, _| ())
+}
+
+/// Checks a CONFIG.toml, additionally running a custom lint on each division.
+///
+/// This allows project-specific rules to be checked without modifying this crate.
+pub fn check_config_with(
+    config: PathBuf,
+    timed: bool,
+    division_lint: DivisionLint,
+) -> Result<CheckResult, Box<dyn Error>> {
+    check_config_with_options(config, timed, ParseOptions::default(), division_lint)
+}
+
+/// Checks a CONFIG.toml like [`check_config_with`], with non-default parsing options.
+pub fn check_config_with_options(
+    config: PathBuf,
+    timed: bool,
+    opts: ParseOptions,
+    division_lint: DivisionLint,
+) -> Result<CheckResult, Box<dyn Error>> {
+    let start = timed.then(Instant::now);
+    let federation = federation_from_path(&config);
+    let mut report = Report::new(config);
+
+    // TOML needs the whole document. This sizes the String from the file metadata.
+    let config_str = std::fs::read_to_string(&report.path)?;
+
+    // Parse the entire string into TOML Value types.
+    let root = match config_str.parse::<Value>() {
+        Ok(root) => root,
+        Err(e) => {
+            report_toml_error(&config_str, &e, &mut report);
+            return Ok(CheckResult {
+                report,
+                config: None,
+                elapsed: start.map(|instant| instant.elapsed()),
+                federation,
+            });
+        }
+    };
+    let mut result = parse_config(&root, report, opts, division_lint)?;
+    if let Some(config) = &mut result.config {
+        config.doc_comments = extract_doc_comments(&config_str);
+    }
+    result.elapsed = start.map(|instant| instant.elapsed());
+    result.federation = federation;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report_count::ReportCount;
+
+    /// Creates a division that accepts lifters of any age.
+    fn open_division(name: &str) -> DivisionConfig {
+        DivisionConfig {
+            name: name.to_string(),
+            min: Age::Exact(0),
+            max: Age::Exact(255),
+            sex: None,
+            equipment: None,
+            tested: None,
+            place: None,
+            records_only: None,
+            fallback: false,
+            age_mode: AgeMode::Age,
+            sort_order: None,
+            level: None,
+            born_after: None,
+            born_before: None,
+        }
+    }
+
+    /// Parses a CONFIG.toml from a string, returning the Config and message counts.
+    fn parse_str(toml: &str) -> (Option<Config>, ReportCount) {
+        let root = toml.parse::<Value>().unwrap();
+        let report = Report::new(PathBuf::from("[inline]/CONFIG.toml"));
+        let result = parse_config(&root, report, ParseOptions::default(), &mut |_, _| ()).unwrap();
+        (result.config, result.report.count_messages())
+    }
+
+    /// Creates a Config with the given divisions and weightclasses.
+    fn config_from_parts(
+        divisions: Vec<DivisionConfig>,
+        weightclasses: Vec<WeightClassConfig>,
+    ) -> Config {
+        Config::new(None, divisions, weightclasses, vec![], vec![], None)
+    }
+
+    #[test]
+    fn sanity_check_valid() {
+        let weightclasses = vec![WeightClassConfig {
+            name: "default_M".to_string(),
+            classes: vec!["90".parse().unwrap(), "90+".parse().unwrap()],
+            date_ranges: vec![(
+                Date::from_parts(1900, 01, 01),
+                Date::from_parts(2999, 01, 01),
+            )],
+            sex: Sex::M,
+            divisions: Some(vec![0]),
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        }];
+        let config = config_from_parts(vec![open_division("Open")], weightclasses);
+        assert!(config.sanity_check().is_empty());
+    }
+
+    #[test]
+    fn sanity_check_invalid() {
+        let mut backwards = open_division("Juniors");
+        backwards.min = Age::Exact(23);
+        backwards.max = Age::Exact(19);
+
+        let weightclasses = vec![WeightClassConfig {
+            name: "default_M".to_string(),
+            classes: vec!["90+".parse().unwrap(), "90".parse().unwrap()],
+            date_ranges: vec![(
+                Date::from_parts(1900, 01, 01),
+                Date::from_parts(2999, 01, 01),
+            )],
+            sex: Sex::M,
+            divisions: Some(vec![5]),
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        }];
+
+        let divisions = vec![open_division("Open"), open_division("Open"), backwards];
+        let errors = config_from_parts(divisions, weightclasses).sanity_check();
+        assert_eq!(errors.len(), 4);
+        assert!(errors[0].contains("must be unique"));
+        assert!(errors[1].contains("invalid age range"));
+        assert!(errors[2].contains("occurs before"));
+        assert!(errors[3].contains("Invalid division index"));
+    }
+
+    #[test]
+    fn is_consistent() {
+        let weightclasses = vec![WeightClassConfig {
+            name: "default_M".to_string(),
+            classes: vec!["90".parse().unwrap(), "90+".parse().unwrap()],
+            date_ranges: vec![(
+                Date::from_parts(1900, 01, 01),
+                Date::from_parts(2999, 01, 01),
+            )],
+            sex: Sex::M,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        }];
+        let config = config_from_parts(vec![open_division("Open")], weightclasses);
+        assert!(config.is_consistent());
+        config.assert_consistent();
+
+        // Divisions like Open and Juniors may overlap, but an inverted range may not.
+        let mut backwards = open_division("Juniors");
+        backwards.min = Age::Exact(23);
+        backwards.max = Age::Exact(19);
+        let config = config_from_parts(vec![open_division("Open"), backwards], vec![]);
+        assert!(!config.is_consistent());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid age range")]
+    fn assert_consistent_panics() {
+        let mut backwards = open_division("Juniors");
+        backwards.min = Age::Exact(23);
+        backwards.max = Age::Exact(19);
+        config_from_parts(vec![backwards], vec![]).assert_consistent();
+    }
+
+    #[test]
+    fn records_only() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            record = { name = "Record", min = 0, max = 999, records_only = "Yes" }
+            bad = { name = "Bad", min = 0, max = 999, records_only = "Maybe" }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert_eq!(count.errors(), 1);
+
+        let config = config.unwrap();
+        let records_only = |name: &str| {
+            let division = config.division_by_name(name).unwrap();
+            division.records_only
+        };
+        assert_eq!(records_only("Open"), None);
+        assert_eq!(records_only("Record"), Some(true));
+        assert_eq!(records_only("Bad"), None);
+    }
+
+    #[test]
+    fn check_config_timing() {
+        let path = std::env::temp_dir().join(format!("checker_{}_timing.toml", std::process::id()));
+        std::fs::write(&path, "[divisions]\n[weightclasses]\n[exemptions]\n").unwrap();
+
+        let untimed = check_config(path.clone(), false).unwrap();
+        assert!(untimed.config.is_some());
+        assert!(untimed.elapsed.is_none());
+
+        let timed = check_config(path.clone(), true).unwrap();
+        assert!(timed.config.is_some());
+        assert!(timed.elapsed.is_some());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn division_age_none() {
+        let divisions = |min: &str, max: &str| {
+            let toml = format!(
+                "[divisions]\nopen = {{ name = \"Open\", min = {min}, max = {max} }}\n\
+                 [weightclasses]\n[exemptions]\n"
+            );
+            parse_str(&toml).1
+        };
+
+        // The canonical open-age range is accepted silently.
+        let count = divisions("0", "999");
+        assert!(!count.any());
+
+        // Missing both ages is an error.
+        let count = divisions("\"\"", "\"\"");
+        assert_eq!(count.errors(), 1);
+        assert_eq!(count.warnings(), 0);
+
+        // Missing only one age is a warning.
+        let count = divisions("18", "\"\"");
+        assert_eq!(count.errors(), 0);
+        assert_eq!(count.warnings(), 1);
+        let count = divisions("\"\"", "23");
+        assert_eq!(count.errors(), 0);
+        assert_eq!(count.warnings(), 1);
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let toml = r#"
+            [options]
+            valid_since = "2001-01-01"
+            federation = "USAPL"
+            [divisions]
+            open = { name = "Raw Open", min = 0, max = 999, equipment = ["Raw", "Wraps"] }
+            guest = { name = "Guest", min = 0, max = 999, place = "G", tested = "Yes" }
+            teen = { name = "Teen", min = 13, max = 19, sex = "M" }
+            [weightclasses]
+            [weightclasses.default_M]
+            classes = ["59", "66", "74", "83", "93", "93+"]
+            date_range = ["2000-01-01", "2030-12-31"]
+            sex = "M"
+            [weightclasses.teen_M]
+            classes = ["47", "52", "56.5", "56.5+"]
+            date_range = ["2000-01-01", "2030-12-31"]
+            sex = "M"
+            divisions = ["Teen"]
+            [rulesets]
+            default = { ruleset = ["CombineRawAndWraps"], date_range = ["2000-01-01", "2030-12-31"] }
+            [exemptions]
+            "1901" = ["ExemptLiftOrder", "ExemptAge"]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+
+        let json = serde_json::to_string(&config).unwrap();
+        let reloaded: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&reloaded).unwrap(), json);
+
+        // The lookup structures are rebuilt on load.
+        let date = Date::from_parts(2019, 06, 01);
+        let wc =
+            reloaded.effective_weightclasses_for_entry(Sex::M, Equipment::Raw, date, Some("Teen"));
+        assert_eq!(wc.map(|wc| wc.name.as_str()), Some("teen_M"));
+        assert!(reloaded.is_exempted("1901", Exemption::ExemptAge));
+        let guest = reloaded
+            .divisions
+            .iter()
+            .find(|d| d.name == "Guest")
+            .unwrap();
+        assert_eq!(guest.place, Some(Place::G));
+    }
+
+    #[test]
+    fn scoring() {
+        let parse_scoring = |section: &str| {
+            let toml = format!("[divisions]\n[weightclasses]\n[exemptions]\n{section}");
+            parse_str(&toml)
+        };
+
+        // The section is optional.
+        let (config, count) = parse_scoring("");
+        assert!(!count.any());
+        assert!(config.unwrap().scoring().is_none());
+
+        // Known formulas are accepted, and parameters are stored.
+        let (config, count) =
+            parse_scoring("[scoring]\nformula = \"Dots\"\nparams = { a = 1.5, b = 2 }");
+        assert!(!count.any());
+        let config = config.unwrap();
+        let scoring = config.scoring().unwrap();
+        assert_eq!(scoring.formula, "Dots");
+        assert_eq!(scoring.params.get("a"), Some(&1.5));
+        assert_eq!(scoring.params.get("b"), Some(&2.0));
+
+        // Unknown formulas are an error.
+        let (config, count) = parse_scoring("[scoring]\nformula = \"Flubber\"");
+        assert_eq!(count.errors(), 1);
+        assert!(config.unwrap().scoring().is_none());
+
+        // Parameters must be numbers.
+        let (_, count) = parse_scoring("[scoring]\nformula = \"Wilks\"\nparams = { a = \"x\" }");
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn weightclass_date_floor() {
+        let weightclasses = |date_min: &str, metadata: &str| {
+            let toml = format!(
+                "[divisions]\n[weightclasses]\n\
+                 default_M = {{ classes = [\"60\", \"75\", \"90\", \"90+\"], sex = \"M\", \
+                 date_range = [\"{date_min}\", \"2030-12-31\"] }}\n[exemptions]\n{metadata}"
+            );
+            parse_str(&toml).1
+        };
+
+        assert!(!weightclasses("1950-01-01", "").any());
+        assert!(!weightclasses("2001-06-01", "").any());
+
+        // Dates before the start of the sport are errors.
+        assert_eq!(weightclasses("1949-12-31", "").errors(), 1);
+        assert_eq!(weightclasses("1800-01-01", "").errors(), 1);
+        assert_eq!(weightclasses("0000-01-01", "").errors(), 1);
+
+        // Dates before the federation was founded are suspicious, if it's known.
+        let founded = "[metadata]\nfounded = \"1981-06-01\"";
+        let count = weightclasses("1970-01-01", founded);
+        assert_eq!((count.errors(), count.warnings()), (0, 1));
+        assert!(!weightclasses("1981-06-01", founded).any());
+
+        // The founding date must be a real date.
+        let count = weightclasses("2001-06-01", "[metadata]\nfounded = 1981");
+        assert_eq!(count.errors(), 1);
+        let count = weightclasses("2001-06-01", "[metadata]\nfounded = \"1981-02-30\"");
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn diff() {
+        let base = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            teen = { name = "Teen", min = 13, max = 19 }
+            [weightclasses]
+            default_M = { classes = ["59", "66", "74", "83", "93", "93+"], date_range = ["2000-01-01", "2030-12-31"], sex = "M" }
+            teen_M = { classes = ["56", "56+"], date_range = ["2000-01-01", "2030-12-31"], sex = "M", divisions = ["Teen"] }
+            [exemptions]
+            "1901" = ["ExemptAge"]
+        "#;
+        let parse = |toml: &str| parse_str(toml).0.unwrap();
+        let old = parse(base);
+
+        // Identical configs are equal and have no differences.
+        let same = parse(base);
+        assert_eq!(old, same);
+        assert!(old.diff(&same).is_empty());
+
+        // Divisions.
+        let new = parse(
+            &base
+                .replace("max = 19", "max = 18")
+                .replace(
+                    "teen = ",
+                    "sub = { name = \"Sub-Juniors\", min = 14, max = 18 }\nteen = ",
+                )
+                .replace("open = { name = \"Open\", min = 0, max = 999 }", ""),
+        );
+        assert_ne!(old, new);
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.divisions,
+            vec![
+                ConfigChange::Removed("Open".into()),
+                ConfigChange::Changed("Teen".into()),
+                ConfigChange::Added("Sub-Juniors".into()),
+            ]
+        );
+
+        // Reordering divisions doesn't change weightclasses that refer to them.
+        assert!(diff.weightclasses.is_empty());
+        assert!(diff.exemptions.is_empty());
+
+        // Weightclasses.
+        let new = parse(
+            &base
+                .replace("\"93\", \"93+\"", "\"93\", \"105\", \"105+\"")
+                .replace("teen_M", "teen_F")
+                .replace("\"M\", divisions", "\"F\", divisions"),
+        );
+        assert_eq!(
+            old.diff(&new).weightclasses,
+            vec![
+                ConfigChange::Changed("default_M".into()),
+                ConfigChange::Removed("teen_M".into()),
+                ConfigChange::Added("teen_F".into()),
+            ]
+        );
+
+        // Marking a class as provisional changes its set.
+        let new = parse(&base.replace("\"56+\"", "{ kg = \"56+\", provisional = true }"));
+        assert_eq!(
+            old.diff(&new).weightclasses,
+            vec![ConfigChange::Changed("teen_M".into())]
+        );
+
+        // So does citing a source for it.
+        let new = parse(&base.replace(
+            "divisions = [\"Teen\"]",
+            "divisions = [\"Teen\"], source = \"https://example.com/rules.pdf\"",
+        ));
+        assert_eq!(
+            old.diff(&new).weightclasses,
+            vec![ConfigChange::Changed("teen_M".into())]
+        );
+
+        // Exemptions.
+        let new = parse(&base.replace(
+            "\"1901\" = [\"ExemptAge\"]",
+            "\"1901\" = [\"ExemptLiftOrder\"]\n\"1902\" = [\"ExemptAge\"]",
+        ));
+        assert_eq!(
+            old.diff(&new).exemptions,
+            vec![
+                ConfigChange::Changed("1901".into()),
+                ConfigChange::Added("1902".into()),
+            ]
+        );
+        let new = parse(&base.replace("\"1901\" = [\"ExemptAge\"]", ""));
+        assert_eq!(
+            old.diff(&new).exemptions,
+            vec![ConfigChange::Removed("1901".into())]
+        );
+
+        // Invalid division indices in programmatically-built Configs don't panic.
+        let invalid = config_from_parts(
+            vec![],
+            vec![WeightClassConfig {
+                divisions: Some(vec![3]),
+                ..parse(base).weightclasses.remove(1)
+            }],
+        );
+        assert!(invalid.diff(&invalid).is_empty());
+        assert_eq!(
+            invalid.diff(&old).weightclasses,
+            vec![
+                ConfigChange::Changed("teen_M".into()),
+                ConfigChange::Added("default_M".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn age_contains() {
+        let division = |min: Age, max: Age| DivisionConfig {
+            min,
+            max,
+            ..open_division("Test")
+        };
+        let (exact, approx) = (Age::Exact, Age::Approximate);
+
+        // Exact bounds.
+        let masters = division(exact(38), exact(42));
+        assert!(!masters.age_contains(exact(37)));
+        assert!(masters.age_contains(exact(38)));
+        assert!(masters.age_contains(exact(40)));
+        assert!(masters.age_contains(exact(42)));
+        assert!(!masters.age_contains(exact(43)));
+        assert!(!masters.age_contains(approx(37)));
+        assert!(masters.age_contains(approx(38)));
+        assert!(masters.age_contains(approx(40)));
+        assert!(masters.age_contains(approx(41)));
+        assert!(!masters.age_contains(approx(42)));
+        assert!(!masters.age_contains(Age::None));
+
+        // Approximate ages must fit entirely.
+        let older = division(exact(41), exact(45));
+        assert!(!older.age_contains(approx(40)));
+        assert!(older.age_contains(approx(41)));
+
+        // Approximate bounds, as for IPF Juniors (19.5-22.5).
+        let juniors = division(approx(19), approx(22));
+        assert!(!juniors.age_contains(exact(18)));
+        assert!(juniors.age_contains(exact(19)));
+        assert!(juniors.age_contains(exact(23)));
+        assert!(!juniors.age_contains(exact(24)));
+        assert!(!juniors.age_contains(approx(18)));
+        assert!(juniors.age_contains(approx(19)));
+        assert!(juniors.age_contains(approx(22)));
+        assert!(!juniors.age_contains(approx(23)));
+        assert!(!juniors.age_contains(Age::None));
+
+        // Mixed bounds.
+        let mixed = division(exact(20), approx(23));
+        assert!(!mixed.age_contains(exact(19)));
+        assert!(mixed.age_contains(exact(24)));
+        assert!(mixed.age_contains(approx(23)));
+        assert!(!mixed.age_contains(approx(24)));
+
+        // Missing bounds are open.
+        let open_top = division(exact(40), Age::None);
+        assert!(!open_top.age_contains(exact(39)));
+        assert!(open_top.age_contains(exact(255)));
+        assert!(open_top.age_contains(approx(254)));
+        let open_bottom = division(Age::None, exact(18));
+        assert!(open_bottom.age_contains(exact(0)));
+        assert!(!open_bottom.age_contains(approx(18)));
+        assert!(!open_bottom.age_contains(Age::None));
+
+        // The canonical open division contains everybody with a known age.
+        let open = division(exact(0), exact(255));
+        assert!(open.age_contains(exact(0)));
+        assert!(open.age_contains(approx(254)));
+        assert!(open.age_contains(approx(255)));
+    }
+
+    #[test]
+    fn num_divisions() {
+        let division = |name: &str, sex: Option<Sex>| DivisionConfig {
+            sex,
+            ..open_division(name)
+        };
+
+        // All unrestricted.
+        let config = config_from_parts(
+            vec![division("Open", None), division("Juniors", None)],
+            vec![],
+        );
+        assert_eq!(config.num_divisions_for_sex(Sex::M), 2);
+        assert_eq!(config.num_divisions_for_sex(Sex::F), 2);
+        assert_eq!(config.num_divisions_unrestricted(), 2);
+
+        // All restricted.
+        let config = config_from_parts(
+            vec![
+                division("Men", Some(Sex::M)),
+                division("Women", Some(Sex::F)),
+                division("Women Juniors", Some(Sex::F)),
+            ],
+            vec![],
+        );
+        assert_eq!(config.num_divisions_for_sex(Sex::M), 1);
+        assert_eq!(config.num_divisions_for_sex(Sex::F), 2);
+        assert_eq!(config.num_divisions_for_sex(Sex::Mx), 0);
+        assert_eq!(config.num_divisions_unrestricted(), 0);
+
+        // Mixed.
+        let config = config_from_parts(
+            vec![
+                division("Open", None),
+                division("Men", Some(Sex::M)),
+                division("Women", Some(Sex::F)),
+            ],
+            vec![],
+        );
+        assert_eq!(config.num_divisions_for_sex(Sex::M), 2);
+        assert_eq!(config.num_divisions_for_sex(Sex::F), 2);
+        assert_eq!(config.num_divisions_for_sex(Sex::Mx), 1);
+        assert_eq!(config.num_divisions_unrestricted(), 1);
+
+        // No divisions.
+        let config = config_from_parts(vec![], vec![]);
+        assert_eq!(config.num_divisions_for_sex(Sex::M), 0);
+        assert_eq!(config.num_divisions_unrestricted(), 0);
+    }
+
+    #[test]
+    fn weightclass_sex_convention() {
+        // Returns the number of sex warnings, which are only produced as heuristics.
+        let sex_warnings = |toml: &str| {
+            let (config, report) = parse_str(toml);
+            assert!(!report.any());
+            let warnings = config.unwrap().heuristic_warnings();
+            warnings.iter().filter(|w| w.contains(" is for ")).count()
+        };
+        let weightclasses = |sex: &str, classes: &str| {
+            sex_warnings(&format!(
+                "[divisions]\n[weightclasses]\n\
+                 set = {{ classes = [{classes}], sex = \"{sex}\", \
+                 date_range = [\"2000-01-01\", \"2030-12-31\"] }}\n[exemptions]\n"
+            ))
+        };
+        let ipf_men = r#""59", "66", "74", "83", "93", "105", "120", "120+""#;
+        let ipf_women = r#""47", "52", "57", "63", "69", "76", "84", "84+""#;
+
+        // Typical sets are accepted.
+        assert_eq!(weightclasses("M", ipf_men), 0);
+        assert_eq!(weightclasses("F", ipf_women), 0);
+        let heavy_women = r#""47", "52", "57", "63", "72", "84", "100", "110", "110+""#;
+        assert_eq!(weightclasses("F", heavy_women), 0);
+
+        // Sets that look like they belong to the other sex are suspicious.
+        let count = weightclasses(
+            "F",
+            r#""47", "52", "57", "63", "72", "84", "100", "125", "140", "140+""#,
+        );
+        assert_eq!(count, 1);
+        assert_eq!(weightclasses("M", r#""44", "48", "52", "52+""#), 1);
+
+        // Youth sets for specific divisions may top out low.
+        let toml = r#"
+            [divisions]
+            youth = { name = "Youth", min = 8, max = 12 }
+            [weightclasses]
+            youth_M = { classes = ["30", "35", "40", "40+"], sex = "M", date_range = ["2000-01-01", "2030-12-31"], divisions = ["Youth"] }
+            [exemptions]
+        "#;
+        assert_eq!(sex_warnings(toml), 0);
+
+        // Mx sets are not checked.
+        assert_eq!(weightclasses("Mx", r#""44", "48", "52", "52+""#), 0);
+    }
+
+    #[test]
+    fn exemption_folders() {
+        assert_eq!(edit_distance("1901", "1901"), 0);
+        assert_eq!(edit_distance("1901", "1902"), 1);
+        assert_eq!(edit_distance("1901", "19011"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+
+        let dir = std::env::temp_dir().join(format!("checker_{}_folders", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        for folder in ["1901", "2104-nationals", "2201"] {
+            std::fs::create_dir_all(dir.join(folder)).unwrap();
+            std::fs::write(dir.join(folder).join("entries.csv"), "").unwrap();
+            std::fs::write(dir.join(folder).join("meet.csv"), "").unwrap();
+        }
+        std::fs::create_dir_all(dir.join("1902")).unwrap();
+        std::fs::write(dir.join("1902").join("meet.csv"), "").unwrap();
+        std::fs::create_dir_all(dir.join("1903")).unwrap();
+
+        let exempt = |folders: &[&str]| {
+            let mut toml = String::from("[divisions]\n[weightclasses]\n[exemptions]\n");
+            for folder in folders {
+                toml += &format!("\"{folder}\" = [\"ExemptAge\"]\n");
+            }
+            let config = parse_str(&toml).0.unwrap();
+            let mut report = Report::new(dir.join("CONFIG.toml"));
+            check_exemption_folders(&config, &dir, &mut report);
+            report
+        };
+
+        // Existing folders are fine.
+        assert!(!exempt(&["1901", "2104-nationals"]).has_messages());
+
+        // Renamed folders are errors, with a suggested fix.
+        let report = exempt(&["2104-natonals", "1901"]);
+        assert_eq!(report.count_messages().errors(), 1);
+        match &report.messages[0] {
+            Message::Error(s) => assert!(s.ends_with("renaming it to '2104-nationals'")),
+            m => panic!("unexpected {m:?}"),
+        }
+
+        // Folders without meet data are warned about, listing what's missing.
+        let report = exempt(&["1902", "1903"]);
+        let count = report.count_messages();
+        assert_eq!((count.errors(), count.warnings()), (0, 2));
+        match &report.messages[0] {
+            Message::Warning(s) => assert!(s.contains("missing entries.csv:")),
+            m => panic!("unexpected {m:?}"),
+        }
+        match &report.messages[1] {
+            Message::Warning(s) => assert!(s.contains("missing entries.csv and meet.csv")),
+            m => panic!("unexpected {m:?}"),
+        }
+
+        // Folders with no similar neighbor are errors without a suggestion.
+        let report = exempt(&["worlds"]);
+        assert_eq!(report.count_messages().errors(), 1);
+        match &report.messages[0] {
+            Message::Error(s) => assert!(s.ends_with("does not exist")),
+            m => panic!("unexpected {m:?}"),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fallback_division() {
+        let toml = r#"
+            [divisions]
+            junior = { name = "Junior", min = 14, max = 23 }
+            masters = { name = "Masters", min = 40, max = 999 }
+            other = { name = "Other", min = 1, max = 5, fallback = true }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+        let division = |sex, age| config.division_for(sex, age).map(|d| d.name.as_str());
+
+        // Ordinary divisions are preferred.
+        assert_eq!(division(Sex::M, Age::Exact(20)), Some("Junior"));
+        assert_eq!(division(Sex::F, Age::Exact(50)), Some("Masters"));
+
+        // Lifters matching nothing else get the fallback, whatever its own ages.
+        assert_eq!(division(Sex::M, Age::Exact(30)), Some("Other"));
+        assert_eq!(division(Sex::Mx, Age::None), Some("Other"));
+
+        // Without a fallback, nothing else matches.
+        let (config, _) = parse_str(&toml.replace("fallback = true", "fallback = false"));
+        assert!(config
+            .unwrap()
+            .division_for(Sex::M, Age::Exact(30))
+            .is_none());
+
+        // A second fallback for the same sex is an error.
+        let toml = r#"
+            [divisions]
+            other = { name = "Other", min = 0, max = 999, fallback = true }
+            other_f = { name = "Other F", min = 0, max = 999, sex = "F", fallback = true }
+            other_m = { name = "Other M", min = 0, max = 999, sex = "M", fallback = false }
+            [weightclasses]
+            [exemptions]
+        "#;
+        assert_eq!(parse_str(toml).1.errors(), 1);
+
+        // Fallbacks for different sexes may coexist.
+        let toml = r#"
+            [divisions]
+            other_f = { name = "Other F", min = 0, max = 999, sex = "F", fallback = true }
+            other_m = { name = "Other M", min = 0, max = 999, sex = "M", fallback = true }
+            [weightclasses]
+            [exemptions]
+        "#;
+        assert!(!parse_str(toml).1.any());
+    }
+
+    #[test]
+    fn division_by_name() {
+        let config = config_from_parts(
+            vec![
+                open_division("Open"),
+                open_division("Juniors"),
+                DivisionConfig {
+                    sex: Some(Sex::F),
+                    ..open_division("Open")
+                },
+            ],
+            vec![],
+        );
+
+        assert_eq!(config.division_by_name("Juniors").unwrap().name, "Juniors");
+        assert!(config.contains_division("Juniors"));
+        assert!(config.division_by_name("Masters").is_none());
+        assert!(!config.contains_division("Masters"));
+        assert!(!config.contains_division("open"));
+
+        // Duplicated names resolve to the first division, like a linear search.
+        let open = config.division_by_name("Open").unwrap();
+        assert_eq!(open.sex, None);
+
+        // The index agrees with a linear search for every division.
+        for division in &config.divisions {
+            let found = config.divisions.iter().find(|d| d.name == division.name);
+            assert_eq!(config.division_by_name(&division.name), found);
+        }
+    }
+
+    #[test]
+    fn doc_comments() {
+        let toml = "#: Configuration for the test federation.\n\
+                    #: Rules follow the 2019 rulebook.\n\
+                    # An ordinary comment.\n\
+                    [divisions]\n\
+                    \x20   #: Indented comments count too.\n\
+                    open = { name = \"Open\", min = 0, max = 999 } #: Trailing comments don't.\n\
+                    #:\n\
+                    [weightclasses]\n\
+                    [exemptions]\n";
+
+        assert_eq!(
+            extract_doc_comments(toml),
+            vec![
+                "Configuration for the test federation.\nRules follow the 2019 rulebook.",
+                "Indented comments count too.",
+                "",
+            ]
+        );
+        assert!(extract_doc_comments("[divisions]\n# Nothing here.\n").is_empty());
+
+        // The comments are stored on the Config when checking a file.
+        let path = std::env::temp_dir().join(format!("checker_{}_doc.toml", std::process::id()));
+        std::fs::write(&path, toml).unwrap();
+        let config = check_config(path.clone(), false).unwrap().config.unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(config.doc_comments, extract_doc_comments(toml));
+    }
+
+    #[test]
+    fn weightclass_ordering() {
+        let errors = |classes: &[&str]| {
+            let classes: Vec<WeightClassKg> = classes.iter().map(|c| c.parse().unwrap()).collect();
+            weightclass_ordering_errors("x", &classes)
+        };
+
+        assert!(errors(&["75", "82.5", "90", "90+"]).is_empty());
+        assert_eq!(
+            errors(&["75", "82.5", "82.5", "90+"]),
+            ["WeightClassKg '82.5' is duplicated in [weightclasses.x]"]
+        );
+        assert_eq!(
+            errors(&["75", "90", "82.5", "90+"]),
+            ["WeightClassKg '90' occurs before '82.5' in [weightclasses.x]"]
+        );
+        assert_eq!(
+            errors(&["90+", "90+"]),
+            ["WeightClassKg '90+' is duplicated in [weightclasses.x]"]
+        );
+    }
+
+    #[test]
+    fn weightclass_gaps() {
+        let gaps = |classes: &[&str]| {
+            let classes: Vec<WeightClassKg> = classes.iter().map(|c| c.parse().unwrap()).collect();
+            weightclass_gap_warnings("test", &classes).len()
+        };
+
+        // Gradual ladders are fine, including the open class.
+        assert_eq!(
+            gaps(&["52", "56", "60", "67.5", "75", "82.5", "90", "90+"]),
+            0
+        );
+        assert_eq!(gaps(&["110", "125", "140", "140+"]), 0);
+        assert_eq!(
+            gaps(&["59", "66", "74", "83", "93", "105", "120", "120+"]),
+            0
+        );
+
+        // Large absolute gaps are warned about.
+        assert_eq!(gaps(&["75", "90", "140", "140+"]), 1);
+
+        // Large relative gaps are warned about, even when small in kilograms.
+        assert_eq!(gaps(&["30", "40", "50", "50+"]), 1);
+
+        // Each gap is reported.
+        assert_eq!(gaps(&["60", "67.5", "100", "110", "140"]), 2);
+
+        // The warning is opt-in, so it doesn't surface during parsing.
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            default_M = { classes = ["60", "75", "90", "140", "140+"], date_range = ["2000-01-01", "2099-12-31"], sex = "M" }
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let warnings = config.unwrap().heuristic_warnings();
+        assert_eq!(
+            warnings.iter().filter(|w| w.contains("a gap of")).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn entries_against_config() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999, equipment = ["Raw", "Wraps"] }
+            juniors = { name = "Juniors", min = 14, max = 23, tested = "Yes" }
+            [weightclasses]
+            default_M = { classes = ["75", "90", "90+"], date_range = ["2000-01-01", "2019-12-31"], sex = "M" }
+            default_F = { classes = ["52", "63", "72", "72+"], date_range = ["2000-01-01", "2099-12-31"], sex = "F" }
+            [exemptions]
+            "2001" = ["ExemptDivision", "ExemptWeightClassConsistency"]
+        "#;
+        let config = parse_str(toml).0.unwrap();
+        let entry = |age, equipment, tested, date: &str| EntryLite {
+            meet_folder: "1901".into(),
+            age: Age::Exact(age),
+            sex: Sex::M,
+            equipment,
+            tested,
+            bodyweightkg: WeightKg::from_i32(80),
+            date: date.parse().unwrap(),
+            birthdate: None,
+        };
+        let errors = |entries: Vec<EntryLite>| {
+            check_entries_against_config(entries.into_iter(), &config)
+                .count_messages()
+                .errors()
+        };
+
+        // Entries with a division and a weightclass are fine.
+        assert_eq!(
+            errors(vec![entry(30, Equipment::Raw, None, "2010-05-01")]),
+            0
+        );
+        assert_eq!(
+            errors(vec![entry(20, Equipment::Single, Some(true), "2010-05-01")]),
+            0
+        );
+        assert_eq!(
+            errors(vec![EntryLite {
+                age: Age::None,
+                ..entry(0, Equipment::Raw, None, "2010-05-01")
+            }]),
+            0
+        );
+
+        // An equipped, untested open lifter has no division.
+        assert_eq!(
+            errors(vec![entry(
+                30,
+                Equipment::Single,
+                Some(false),
+                "2010-05-01"
+            )]),
+            1
+        );
+
+        // Men have no weightclasses after 2019.
+        assert_eq!(
+            errors(vec![entry(30, Equipment::Raw, None, "2020-05-01")]),
+            1
+        );
+
+        // Exempted meets skip both checks.
+        let exempt = EntryLite {
+            meet_folder: "2001".into(),
+            ..entry(30, Equipment::Single, Some(false), "2020-05-01")
+        };
+        assert_eq!(errors(vec![exempt]), 0);
+    }
+
+    #[test]
+    fn weightclass_counts() {
+        let config = |men: &str, women: &str, women_dates: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                [weightclasses]
+                men = {{ classes = {men}, date_range = ["2000-01-01", "2009-12-31"], sex = "M" }}
+                women = {{ classes = {women}, date_range = {women_dates}, sex = "F" }}
+                [exemptions]
+                "#
+            );
+            let config = parse_str(&toml).0.unwrap();
+            weightclass_count_warnings(&config.weightclasses).len()
+        };
+        let men = r#"["59", "66", "74", "83", "93", "105", "120", "120+"]"#;
+        let women = r#"["47", "52", "57", "63", "72", "84", "84+"]"#;
+        let same_dates = r#"["2000-01-01", "2009-12-31"]"#;
+
+        // A typical ladder of 8 men's classes and 7 women's classes is fine.
+        assert_eq!(config(men, women, same_dates), 0);
+
+        // Inverted counts are warned about.
+        assert_eq!(config(women, men, same_dates), 1);
+
+        // Sets in effect at different times aren't compared.
+        assert_eq!(config(women, men, r#"["2010-01-01", "2019-12-31"]"#), 0);
+        assert_eq!(config(women, men, r#"["2009-12-31", "2019-12-31"]"#), 1);
+    }
+
+    #[test]
+    fn unbounded_division_ages() {
+        let toml = r#"
+            [divisions]
+            a = { name = "A", min = 0, max = 999 }
+            b = { name = "B", min = 0, max = "999" }
+            c = { name = "C", min = "none", max = "max" }
+            d = { name = "D", min = 40, max = "none" }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+        for division in &config.divisions {
+            assert!(division.max.is_unbounded_max());
+            assert!(division.age_contains(Age::Exact(90)));
+        }
+        assert_eq!(
+            config.division_by_name("C").unwrap().min,
+            Age::unbounded_min()
+        );
+        assert!(!config
+            .division_by_name("D")
+            .unwrap()
+            .age_contains(Age::Exact(39)));
+
+        // Other strings are still errors.
+        let toml = toml.replace(r#"max = "max""#, r#"max = "forever""#);
+        assert_eq!(parse_str(&toml).1.errors(), 1);
+    }
+
+    #[test]
+    fn date_range_string() {
+        let wc = |date_min: &str, date_max: &str| WeightClassConfig {
+            name: "default_M".into(),
+            classes: vec![],
+            date_ranges: vec![(date_min.parse().unwrap(), date_max.parse().unwrap())],
+            sex: Sex::M,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        };
+
+        // Closed ranges show both dates, separated by an en-dash.
+        let closed = wc("2012-01-01", "2017-12-31");
+        assert_eq!(closed.date_range_string(), "2012-01-01\u{2013}2017-12-31");
+        // The only hyphens are the ones inside the dates.
+        assert_eq!(closed.date_range_string().matches('-').count(), 4);
+
+        // Ranges ending at the sentinel are still in use.
+        assert_eq!(
+            wc("2019-01-01", "9999-01-01").date_range_string(),
+            "2019-01-01\u{2013}present"
+        );
+        assert_eq!(
+            wc("2019-01-01", "9999-12-31").date_range_string(),
+            "2019-01-01\u{2013}present"
+        );
+
+        assert_eq!(
+            closed.to_string(),
+            "[weightclasses.default_M] (2012-01-01\u{2013}2017-12-31)"
+        );
+    }
+
+    #[test]
+    fn weightclass_neighbors() {
+        let config = |a: &str, b: &str, b_dates: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                [weightclasses]
+                a = {{ classes = {a}, date_range = ["2000-01-01", "2009-12-31"], sex = "M" }}
+                b = {{ classes = {b}, date_range = {b_dates}, sex = "M" }}
+                [exemptions]
+                "#
+            );
+            parse_str(&toml)
+                .0
+                .unwrap()
+                .weightclass_neighbor_warnings()
+                .len()
+        };
+        let open = r#"["75", "82.5", "90", "100", "110", "110+"]"#;
+        let same_dates = r#"["2000-01-01", "2009-12-31"]"#;
+
+        // Sets that share structure are fine, even if the ends differ.
+        assert_eq!(config(open, open, same_dates), 0);
+        assert_eq!(
+            config(
+                open,
+                r#"["56", "60", "67.5", "75", "82.5", "90", "90+"]"#,
+                same_dates
+            ),
+            0
+        );
+
+        // A shared class with different neighbors on both sides is suspicious.
+        let odd = r#"["67.5", "82.5", "95", "95+"]"#;
+        assert_eq!(config(open, odd, same_dates), 1);
+
+        // Sets from different eras aren't compared.
+        assert_eq!(config(open, odd, r#"["2010-01-01", "2019-12-31"]"#), 0);
+    }
+
+    #[test]
+    fn to_csv_summary() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open, All Ages", min = 0, max = 999 }
+            juniors = { name = "Juniors", min = 19, max = 23, equipment = ["Raw", "Wraps"] }
+            women = { name = "Women", min = 0, max = 999, sex = "F" }
+            [weightclasses]
+            default_M = { classes = ["83", "93", "93+"], date_range = ["2000-01-01", "2009-12-31"], sex = "M" }
+            default_F = { classes = ["63", "72", "72+"], date_range = ["2000-01-01", "2009-12-31"], sex = "F" }
+            juniors_M = { classes = ["74", "83", "83+"], date_range = ["2000-01-01", "2009-12-31"], sex = "M", divisions = ["Juniors"] }
+            equipped_M = { classes = ["82.5", "90", "90+"], date_range = ["2000-01-01", "2009-12-31"], sex = "M", equipment = "Single-ply" }
+            [exemptions]
+        "#;
+        let config = parse_str(toml).0.unwrap();
+        let csv = config.to_csv_summary();
+
+        let mut rdr = csv::Reader::from_reader(csv.as_bytes());
+        let rows: Vec<csv::StringRecord> = rdr.records().map(Result::unwrap).collect();
+        let pairs: Vec<(&str, &str)> = rows.iter().map(|r| (&r[0], &r[5])).collect();
+
+        // Open gets every unrestricted set, Juniors loses the equipped set but gains
+        // its own, and Women only gets the women's set.
+        let expected = [
+            ("Juniors", "default_F"),
+            ("Juniors", "default_M"),
+            ("Juniors", "juniors_M"),
+            ("Open, All Ages", "default_F"),
+            ("Open, All Ages", "default_M"),
+            ("Open, All Ages", "equipped_M"),
+            ("Women", "default_F"),
+        ];
+        assert_eq!(rows.len(), expected.len());
+        for pair in expected {
+            assert!(pairs.contains(&pair), "missing {pair:?}");
+        }
+
+        // Fields are quoted and lists are separated by semicolons.
+        assert!(csv.contains("\"Open, All Ages\""));
+        let juniors = rows.iter().find(|r| &r[5] == "juniors_M").unwrap();
+        assert_eq!(&juniors[1], "19");
+        assert_eq!(&juniors[3], "M");
+        assert_eq!(&juniors[4], "Raw;Wraps");
+        assert_eq!(&juniors[6], "74;83;83+");
+        assert_eq!(&juniors[7], "2000-01-01");
+    }
+
+    #[test]
+    fn check_config_with_division_lint() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            masters = { name = "masters", min = 40, max = 999 }
+            teen = { name = "teen", min = 13, max = 19 }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let path = std::env::temp_dir().join(format!("checker_{}_lint.toml", std::process::id()));
+        std::fs::write(&path, toml).unwrap();
+
+        // A made-up house rule: division names must be capitalized.
+        let mut visited = 0;
+        let mut lint = |division: &DivisionConfig, report: &mut Report| {
+            visited += 1;
+            if division.name.starts_with(char::is_lowercase) {
+                report.warning(format!(
+                    "Division '{}' should be capitalized",
+                    division.name
+                ));
+            }
+        };
+        let result = check_config_with(path.clone(), false, &mut lint).unwrap();
+        assert_eq!(visited, 3);
+        assert_eq!(result.report.count_messages().warnings(), 2);
+        assert!(result.config.is_some());
+
+        // Without the lint, the file is clean.
+        let result = check_config(path.clone(), false).unwrap();
+        assert!(!result.report.count_messages().any());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn warning_baseline() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            [weightclasses]
+            [exemptions]
+            "1901" = []
+            "1902" = []
+        "#;
+        let dir = std::env::temp_dir().join(format!("checker_{}_baseline", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("CONFIG.toml");
+        std::fs::write(&path, toml).unwrap();
+        let warnings = |report: &Report| -> Vec<String> {
+            let mut acc = vec![];
+            for message in &report.messages {
+                if let Message::Warning(s) = message {
+                    acc.push(s.clone());
+                }
+            }
+            acc
+        };
+
+        let check = |path: &PathBuf| {
+            let mut result = check_config(path.clone(), false).unwrap();
+            apply_warning_baseline(&mut result.report);
+            result
+        };
+
+        // Without a baseline, both empty exemptions warn.
+        let result = check(&path);
+        let all = warnings(&result.report);
+        assert_eq!(all.len(), 2);
+
+        // Baselining the first warning still leaves the second.
+        let baseline = format!("warnings = [{:?}]", all[0]);
+        std::fs::write(dir.join("CONFIG.warnings-baseline.toml"), baseline).unwrap();
+        let result = check(&path);
+        assert_eq!(warnings(&result.report), vec![all[1].clone()]);
+        assert_eq!(result.report.count_messages().errors(), 0);
+
+        // Stale baseline entries are noted so they can be removed.
+        let baseline = format!("warnings = [{:?}, {:?}, \"Gone\"]", all[0], all[1]);
+        std::fs::write(dir.join("CONFIG.warnings-baseline.toml"), baseline).unwrap();
+        let result = check(&path);
+        assert!(!result.report.count_messages().any());
+        assert_eq!(result.report.messages.len(), 1);
+
+        // A malformed baseline is an error.
+        std::fs::write(dir.join("CONFIG.warnings-baseline.toml"), "warnings = 3").unwrap();
+        let result = check(&path);
+        assert_eq!(result.report.count_messages().errors(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn approximate_meet_count() {
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            [exemptions]
+            "1902" = ["ExemptAge"]
+            "1901" = ["ExemptLiftOrder"]
+        "#;
+        let mut config = parse_str(toml).0.unwrap();
+        assert_eq!(config.approximate_meet_count(), 2);
+
+        // Programmatically-built Configs may repeat a folder.
+        config.exemptions.push(ExemptionConfig {
+            meet_folder: "1901".to_string(),
+            exemptions: vec![Exemption::ExemptAge],
+        });
+        assert_eq!(config.approximate_meet_count(), 2);
+        let mut folders: Vec<&str> = config.exempted_meet_folders().collect();
+        folders.sort_unstable();
+        assert_eq!(folders, vec!["1901", "1902"]);
+
+        config.exemptions.clear();
+        assert_eq!(config.approximate_meet_count(), 0);
+    }
+
+    #[test]
+    fn exemption_set_for() {
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            [exemptions]
+            "1901" = ["ExemptLiftOrder", "ExemptAge", "ExemptLiftOrder"]
+        "#;
+        let config = parse_str(toml).0.unwrap();
+
+        let ec = &config.exemptions[0];
+        let set = ec.into_exemption_set();
+        assert_eq!(set.len(), 2);
+        assert!(ec.exemptions.iter().all(|e| set.contains(e)));
+
+        assert_eq!(config.exemption_set_for("1901"), set);
+        assert!(config.exemption_set_for("1902").is_empty());
+    }
+
+    #[test]
+    fn provisional_classes() {
+        let weightclasses = |classes: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                [weightclasses]
+                [weightclasses.default_M]
+                classes = {classes}
+                date_range = ["2000-01-01", "2030-12-31"]
+                sex = "M"
+                [exemptions]
+            "#
+            );
+            parse_str(&toml)
+        };
+
+        // Bare and table forms can be mixed.
+        let (config, count) = weightclasses(
+            r#"["59", "66", "74", { kg = 83, provisional = true }, { kg = "93" }, "93+"]"#,
+        );
+        assert!(!count.any());
+        let wc = &config.unwrap().weightclasses[0];
+        assert_eq!(wc.classes.len(), 6);
+        assert_eq!(wc.provisional, vec!["83".parse().unwrap()]);
+        assert!(wc.is_provisional("83".parse().unwrap()));
+        assert!(!wc.is_provisional("93".parse().unwrap()));
+
+        // Ordering is still checked on the kg values.
+        let (_, count) = weightclasses(r#"["59", "74", { kg = 66, provisional = true }, "74+"]"#);
+        assert_eq!(count.errors(), 1);
+
+        // Tables need a kg, and provisional must be a Bool.
+        let (_, count) = weightclasses(r#"["59", { provisional = true }, "59+"]"#);
+        assert_eq!(count.errors(), 1);
+        let (_, count) = weightclasses(r#"["59", { kg = 66, provisional = "Yes" }, "66+"]"#);
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn negative_ages() {
+        let division = |min: &str, max: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                open = {{ name = "Open", min = {min}, max = {max} }}
+                [weightclasses]
+                [exemptions]
+            "#
+            );
+            parse_str(&toml).1
+        };
+
+        assert!(!division("0", "999").any());
+        assert!(!division("19.5", "22.5").any());
+
+        // Negative ages can't be represented, whether exact or approximate.
+        assert_eq!(division("-1", "999").errors(), 1);
+        assert_eq!(division("0", "-1").errors(), 1);
+        assert_eq!(division("-0.5", "22.5").errors(), 1);
+        assert_eq!(division("0", "-22.5").errors(), 1);
+        assert_eq!(division(r#""-1""#, "999").errors(), 1);
+    }
+
+    #[test]
+    fn validate() {
+        let set = |name: &str, sex, classes: &[&str]| WeightClassConfig {
+            name: name.to_string(),
+            classes: classes.iter().map(|c| c.parse().unwrap()).collect(),
+            date_ranges: vec![(
+                Date::from_parts(2000, 01, 01),
+                Date::from_parts(2030, 12, 31),
+            )],
+            sex,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        };
+        let men = ["59", "66", "74", "83", "93", "105", "120", "120+"];
+        let women = ["47", "52", "57", "63", "72", "84", "84+"];
+        let weightclasses = vec![
+            // Swapped sex labels, which is a heuristic.
+            set("default_M", Sex::M, &women),
+            set("default_F", Sex::F, &men),
+            // A 74 with different neighbors in two men's sets, which is a heuristic.
+            set("open_M", Sex::M, &men),
+            set("juniors_M", Sex::M, &["53", "59", "74", "105", "105+"]),
+            // Out of order, which is a structural check.
+            set("broken_M", Sex::M, &["90+", "90"]),
+        ];
+        let mut config = config_from_parts(vec![], weightclasses);
+        config.exemptions = vec![ExemptionConfig {
+            meet_folder: "1901".to_string(),
+            exemptions: vec![Exemption::ExemptDivision],
+        }];
+
+        let messages = |opts| {
+            let mut report = Report::new(PathBuf::from("[inline]/CONFIG.toml"));
+            config.validate(opts, &mut report);
+            report
+                .messages
+                .into_iter()
+                .map(|m| match m {
+                    Message::Error(s) | Message::Warning(s) | Message::Info(s) => s,
+                })
+                .collect::<Vec<String>>()
+        };
+        let has = |messages: &[String], needle: &str| messages.iter().any(|s| s.contains(needle));
+
+        let default = messages(ValidateOptions::default());
+        assert!(has(&default, "has no effect"));
+        assert!(!has(&default, "are the sexes swapped"));
+        assert!(!has(&default, "occurs before"));
+        assert!(!has(&default, "but between"));
+
+        let all = messages(ValidateOptions {
+            structural: true,
+            heuristics: true,
+        });
+        assert!(has(&all, "has no effect"));
+        assert!(has(&all, "are the sexes swapped"));
+        assert!(has(&all, "occurs before"));
+        assert!(has(&all, "but between"));
+    }
+
+    #[test]
+    fn metadata_unsupported() {
+        let divisions = |metadata: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                open = {{ name = "Open", min = 0, max = 999 }}
+                fmp = {{ name = "F-MP", min = 0, max = 999, sex = "F", equipment = ["Multi-ply"] }}
+                mmp = {{ name = "M-MP", min = 0, max = 999, sex = "M", equipment = ["Multi-ply"] }}
+                fraw = {{ name = "F-R", min = 0, max = 999, sex = "F", equipment = ["Raw"] }}
+                [weightclasses]
+                [exemptions]
+                {metadata}
+            "#
+            );
+            parse_str(&toml).1
+        };
+
+        // The check is off without the metadata.
+        assert!(!divisions("").any());
+        assert!(!divisions("[metadata]").any());
+
+        // Only F-MP restricts both to an unsupported combination.
+        let count = divisions(
+            r#"
+            [metadata]
+            unsupported = [{ sex = "F", equipment = ["Multi-ply", "Single-ply"] }]
+        "#,
+        );
+        assert_eq!((count.errors(), count.warnings()), (0, 1));
+
+        // A single equipment may be given as a String.
+        let count = divisions(
+            r#"
+            [metadata]
+            unsupported = [{ sex = "M", equipment = "Multi-ply" }]
+        "#,
+        );
+        assert_eq!((count.errors(), count.warnings()), (0, 1));
+
+        let count = divisions("[metadata]\nunsupported = [{ equipment = \"Raw\" }]");
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn metadata_youth_cutoff() {
+        let divisions = |metadata: &str| {
+            let toml = format!(
+                r#"
+                [divisions]
+                open = {{ name = "Open", min = 0, max = 999 }}
+                youth = {{ name = "Youth", min = 8, max = 13 }}
+                youth2 = {{ name = "Youth 2", min = 12, max = 14 }}
+                subjr = {{ name = "Sub-Juniors", min = 14, max = 18 }}
+                [weightclasses]
+                [exemptions]
+                {metadata}
+            "#
+            );
+            parse_str(&toml).1
+        };
+
+        // The check is off without the cutoff.
+        assert!(!divisions("[metadata]").any());
+
+        // Only divisions named for youths are checked, and the cutoff is inclusive.
+        let count = divisions("[metadata]\nyouth_cutoff = 14");
+        assert_eq!((count.errors(), count.warnings()), (0, 1));
+        let count = divisions("[metadata]\nyouth_cutoff = 12");
+        assert_eq!((count.errors(), count.warnings()), (0, 3));
+        let count = divisions("[metadata]\nyouth_cutoff = 18");
+        assert!(!count.any());
+
+        assert_eq!(divisions("[metadata]\nyouth_cutoff = -1").errors(), 1);
+
+        assert_eq!(divisions("[metadata]\nyouth_cutoff = \"14\"").errors(), 1);
+    }
+
+    #[test]
+    fn metadata_parent_federation() {
+        let parse = |metadata: &str| {
+            let toml =
+                format!("[divisions]\n[weightclasses]\n[exemptions]\n[metadata]\n{metadata}");
+            parse_str(&toml)
+        };
+        let (config, count) = parse("parent_federation = \"IPF\"");
+        assert!(!count.any());
+        assert_eq!(config.unwrap().parent_federation, Some(Federation::IPF));
+
+        let (config, _) = parse("");
+        assert_eq!(config.unwrap().parent_federation, None);
+        assert_eq!(parse("parent_federation = \"Nope\"").1.errors(), 1);
+        assert_eq!(parse("parent_federation = 1").1.errors(), 1);
+
+        // The fourth attempt policy defaults to allowing them.
+        let (config, _) = parse("allow_fourth_attempts = \"WROnly\"");
+        assert_eq!(config.unwrap().fourth_attempts, FourthAttemptPolicy::WROnly);
+        let (config, _) = parse("");
+        assert_eq!(config.unwrap().fourth_attempts, FourthAttemptPolicy::Always);
+        assert_eq!(parse("allow_fourth_attempts = \"Sometimes\"").1.errors(), 1);
+    }
+
+    #[test]
+    fn toml_syntax_errors() {
+        let message = |src: &str| {
+            let e = src.parse::<Value>().unwrap_err();
+            let mut report = Report::new(PathBuf::from("[inline]/CONFIG.toml"));
+            report_toml_error(src, &e, &mut report);
+            assert_eq!(report.messages.len(), 1);
+            match &report.messages[0] {
+                Message::Error(s) => s.clone(),
+                m => panic!("Expected an error, got {m:?}"),
+            }
+        };
+
+        assert_eq!(
+            message("[divisions]\nopen = 1\nopen = 2\n"),
+            " Line 3: Column 1: Key 'open' is defined more than once in [divisions]"
+        );
+        assert_eq!(
+            message("[divisions]\n[weightclasses]\n[divisions]\n"),
+            " Line 3: Column 1: Section [divisions] is defined more than once"
+        );
+        assert_eq!(
+            message("[divisions]\nopen = { name = \"a\", name = \"b\" }\n"),
+            " Line 2: Column 9: Key 'name' is defined more than once"
+        );
+        assert_eq!(
+            message("[divisions]\nopen = { name = \"Open\", min = 0, }\n"),
+            " Line 2: Column 32: Inline tables, in { braces }, can't have a trailing comma"
+        );
+        assert_eq!(
+            message("[exemptions]\n\"1901\" = [\"ExemptAge\",, \"ExemptLiftOrder\"]\n"),
+            " Line 2: Column 23: Arrays can't have an empty item between commas"
+        );
+
+        // Other errors keep the original message.
+        assert!(message("[divisions]\nopen = \"abc\n").contains("TOML syntax error"));
+    }
+
+    #[test]
+    fn federation_from_path() {
+        let fed = |path: &str| super::federation_from_path(Path::new(path));
+        assert_eq!(fed("meet-data/ipf/CONFIG.toml"), Some(Federation::IPF));
+        assert_eq!(
+            fed("/home/user/opl/meet-data/usapl/CONFIG.toml"),
+            Some(Federation::USAPL)
+        );
+
+        // Paths within a meet folder find the enclosing federation.
+        assert_eq!(fed("meet-data/ipf/1901/entries.csv"), Some(Federation::IPF));
+
+        // Unknown or missing folders have no federation.
+        assert_eq!(fed("meet-data/mags/CONFIG.toml"), None);
+        assert_eq!(fed("CONFIG.toml"), None);
+        assert_eq!(fed(""), None);
+
+        // The file name itself is not a folder.
+        assert_eq!(fed("meet-data/ipf"), None);
+    }
+
+    #[test]
+    fn check_config_syntax_error() {
+        let path = std::env::temp_dir().join(format!("checker_{}_syntax.toml", std::process::id()));
+        std::fs::write(&path, "[divisions]\nopen = 1\nopen = 2\n").unwrap();
+        let result = check_config(path.clone(), false).unwrap();
+        assert!(result.config.is_none());
+        assert_eq!(result.report.count_messages().errors(), 1);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn age_mode() {
+        let toml = |mode: &str| {
+            format!(
+                r#"
+                [divisions]
+                u15 = {{ name = "U15", min = 0, max = 14{mode} }}
+                open = {{ name = "Open", min = 15, max = 999 }}
+                [weightclasses]
+                [exemptions]
+            "#
+            )
+        };
+
+        let (config, count) = parse_str(&toml(r#", age_mode = "birthyear""#));
+        assert!(!count.any());
+        let config = config.unwrap();
+        let u15 = config.division_by_name("U15").unwrap();
+        assert_eq!(u15.age_mode, AgeMode::BirthYear);
+
+        // A lifter born in 2010 competing in 2024 is 13 or 14 on the day,
+        // but turns 14 that year either way.
+        assert_eq!(u15.effective_age(Age::Approximate(13)), Age::Exact(14));
+        assert_eq!(
+            config
+                .division_for(Sex::M, Age::Approximate(13))
+                .unwrap()
+                .name,
+            "U15"
+        );
+
+        // A 14-year-old might turn 15 later that year, so isn't definitely U15,
+        // and isn't definitely 15 for Open either.
+        assert_eq!(u15.effective_age(Age::Exact(14)), Age::Approximate(14));
+        assert!(config.division_for(Sex::M, Age::Exact(14)).is_none());
+        assert_eq!(u15.effective_age(Age::None), Age::None);
+
+        // By default, the age on the day is used.
+        let (config, count) = parse_str(&toml(""));
+        assert!(!count.any());
+        let config = config.unwrap();
+        let u15 = config.division_by_name("U15").unwrap();
+        assert_eq!(u15.age_mode, AgeMode::Age);
+        assert_eq!(u15.effective_age(Age::Exact(14)), Age::Exact(14));
+        assert_eq!(
+            config.division_for(Sex::M, Age::Exact(14)).unwrap().name,
+            "U15"
+        );
+
+        let (_, count) = parse_str(&toml(r#", age_mode = "BirthDate""#));
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn multiple_date_ranges() {
+        let parse_ranges = |date_range: &str| {
+            let toml = format!(
+                "[divisions]\n[weightclasses]\nmen = {{ sex = \"M\", \
+                 classes = [\"52\", \"56\", \"60\", \"67.5\", \"75\", \"82.5\", \"90\", \"90+\"], \
+                 date_range = {date_range} }}\n[exemptions]\n"
+            );
+            parse_str(&toml)
+        };
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        // The single-range form still works.
+        let (config, count) = parse_ranges("[\"2000-01-01\", \"2009-12-31\"]");
+        assert_eq!(count.errors(), 0);
+        let config = config.unwrap();
+        let wc = &config.weightclasses[0];
+        assert_eq!(wc.date_ranges, [(date("2000-01-01"), date("2009-12-31"))]);
+
+        // Several ranges are sorted, and dates between them aren't covered.
+        let ranges = "[[\"2015-01-01\", \"9999-01-01\"], [\"2000-01-01\", \"2009-12-31\"]]";
+        let (config, count) = parse_ranges(ranges);
+        assert_eq!(count.errors(), 0);
+        let config = config.unwrap();
+        let wc = &config.weightclasses[0];
+        assert_eq!(
+            wc.date_range_string(),
+            "2000-01-01–2009-12-31, 2015-01-01–present"
+        );
+        assert!(wc.contains_date(date("2005-06-01")));
+        assert!(!wc.contains_date(date("2012-06-01")));
+        assert!(wc.contains_date(date("2020-06-01")));
+
+        let lookup = |d: &str| {
+            config.effective_weightclasses_for_entry(Sex::M, Equipment::Raw, date(d), None)
+        };
+        assert!(lookup("2005-06-01").is_some());
+        assert!(lookup("2012-06-01").is_none());
+        assert!(lookup("2020-06-01").is_some());
+
+        // Overlapping ranges in one set are an error.
+        let ranges = "[[\"2000-01-01\", \"2010-12-31\"], [\"2010-01-01\", \"2020-12-31\"]]";
+        assert_eq!(parse_ranges(ranges).1.errors(), 1);
+
+        // Each range must be a pair.
+        assert_eq!(parse_ranges("[[\"2000-01-01\"]]").1.errors(), 1);
+    }
+
+    #[test]
+    fn division_equipment_name() {
+        let warnings = |division: &str| {
+            let toml =
+                format!("[divisions]\ndiv = {{ {division} }}\n[weightclasses]\n[exemptions]\n");
+            let (config, count) = parse_str(&toml);
+            assert!(!count.any());
+            let warnings = config.unwrap().heuristic_warnings();
+            warnings
+                .iter()
+                .filter(|w| w.contains("its name doesn't say so"))
+                .count()
+        };
+
+        // A restricted division should say so in its name.
+        assert_eq!(
+            warnings("name = \"Open\", min = 0, max = 999, equipment = [\"Raw\"]"),
+            1
+        );
+        let wraps = "name = \"Juniors\", min = 0, max = 999, equipment = [\"Raw\", \"Wraps\"]";
+        assert_eq!(warnings(wraps), 1);
+
+        // Any allowed equipment, spelled out or abbreviated, is enough.
+        assert_eq!(
+            warnings("name = \"Raw Open\", min = 0, max = 999, equipment = [\"Raw\"]"),
+            0
+        );
+        assert_eq!(
+            warnings("name = \"Open-Classic\", min = 0, max = 999, equipment = [\"Raw\"]"),
+            0
+        );
+        assert_eq!(
+            warnings("name = \"F-MP\", min = 0, max = 999, equipment = [\"Multi-ply\"]"),
+            0
+        );
+        let wraps = "name = \"Open Wraps\", min = 0, max = 999, equipment = [\"Raw\", \"Wraps\"]";
+        assert_eq!(warnings(wraps), 0);
+
+        // Words are matched whole, so "Draw" doesn't mention Raw.
+        assert_eq!(
+            warnings("name = \"Draw\", min = 0, max = 999, equipment = [\"Raw\"]"),
+            1
+        );
+
+        // Unrestricted divisions are never flagged.
+        assert_eq!(warnings("name = \"Open\", min = 0, max = 999"), 0);
+
+        let divisions = [DivisionConfig {
+            equipment: Some(vec![Equipment::Raw]),
+            ..open_division("Open")
+        }];
+        let expected = "Division 'Open' only allows Raw, but its name doesn't say so: \
+                        consider a name like 'Raw Open'";
+        assert_eq!(division_equipment_name_warnings(&divisions), [expected]);
+    }
+
+    #[test]
+    fn tested_division_warnings() {
+        let warnings = |divisions: &str| {
+            let toml = format!("[divisions]\n{divisions}\n[weightclasses]\n[exemptions]\n");
+            let (config, count) = parse_str(&toml);
+            assert_eq!(count.errors(), 0);
+            config.unwrap().tested_division_warnings()
+        };
+
+        // Every tested division is mirrored.
+        let mirrored = r#"
+            open = { name = "Open", min = 0, max = 999 }
+            t_open = { name = "T-Open", min = 0, max = 999, tested = "Yes" }
+            junior = { name = "Juniors", min = 20, max = 23, tested = "No" }
+            t_junior = { name = "T-Juniors", min = 20, max = 23, tested = "Yes" }
+        "#;
+        assert!(warnings(mirrored).is_empty());
+
+        // The untested Juniors division was forgotten.
+        let asymmetric = r#"
+            open = { name = "Open", min = 0, max = 999 }
+            t_open = { name = "T-Open", min = 0, max = 999, tested = "Yes" }
+            t_junior = { name = "T-Juniors", min = 20, max = 23, tested = "Yes" }
+        "#;
+        let all = warnings(asymmetric);
+        assert_eq!(all.len(), 1);
+        assert!(all[0].contains("'T-Juniors'"));
+
+        // A different age range or equipment is not a counterpart.
+        let different = r#"
+            junior = { name = "Juniors", min = 20, max = 24 }
+            t_junior = { name = "T-Juniors", min = 20, max = 23, tested = "Yes" }
+            t_raw = { name = "T-Raw Juniors", min = 20, max = 24, tested = "Yes", equipment = ["Raw"] }
+        "#;
+        assert_eq!(warnings(different).len(), 2);
+
+        // Fully-tested federations and guest divisions aren't checked.
+        let fully_tested = r#"
+            t_open = { name = "T-Open", min = 0, max = 999, tested = "Yes" }
+            guest = { name = "Guest", min = 0, max = 999, place = "G" }
+        "#;
+        assert!(warnings(fully_tested).is_empty());
+    }
+
+    #[test]
+    fn years_covered() {
+        let config = |ranges: &[(&str, &str)]| {
+            let weightclasses = ranges
+                .iter()
+                .map(|(min, max)| WeightClassConfig {
+                    name: "default_M".into(),
+                    classes: vec![],
+                    date_ranges: vec![(min.parse().unwrap(), max.parse().unwrap())],
+                    sex: Sex::M,
+                    divisions: None,
+                    equipment: None,
+                    provisional: vec![],
+                    source: None,
+                })
+                .collect();
+            config_from_parts(vec![], weightclasses)
+        };
+
+        // Sets spanning decades, with a gap and an overlap.
+        let decades = config(&[
+            ("1985-01-01", "1992-12-31"),
+            ("1990-01-01", "1993-06-30"),
+            ("2001-01-01", "2003-12-31"),
+        ]);
+        let today = Date::from_parts(2024, 06, 15);
+        let expected: Vec<u32> = (1985..=1993).chain(2001..=2003).collect();
+        assert_eq!(decades.years_covered(today), expected);
+        assert_eq!(decades.first_year(today), Some(1985));
+        assert_eq!(decades.last_year(today), Some(2003));
+
+        // A set within a single year.
+        let single = config(&[("2019-03-01", "2019-11-30")]);
+        assert_eq!(single.years_covered(today), [2019]);
+        assert_eq!(single.first_year(today), single.last_year(today));
+
+        // Sets still in use end this year, not in 9999.
+        let present = config(&[("2020-01-01", "9999-01-01")]);
+        assert_eq!(present.years_covered(today), [2020, 2021, 2022, 2023, 2024]);
+
+        // A set still in use that starts after today covers its first year.
+        let upcoming = config(&[("2026-01-01", "9999-01-01")]);
+        assert_eq!(upcoming.years_covered(today), [2026]);
+
+        // Without weightclasses, nothing is covered.
+        let empty = config(&[]);
+        assert!(empty.years_covered(today).is_empty());
+        assert_eq!(empty.first_year(today), None);
+        assert_eq!(empty.last_year(today), None);
+    }
+
+    #[test]
+    fn class_for_bodyweight() {
+        let set = |classes: &[&str]| WeightClassConfig {
+            name: "default_M".into(),
+            classes: classes.iter().map(|c| c.parse().unwrap()).collect(),
+            date_ranges: vec![(Date::from_parts(2000, 01, 01), WEIGHTCLASS_DATE_PRESENT)],
+            sex: Sex::M,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        };
+        let kg = |s: &str| s.parse::<WeightKg>().unwrap();
+        let class = |s: &str| Some(s.parse::<WeightClassKg>().unwrap());
+
+        let with_shw = set(&["59", "66", "74", "74+"]);
+        assert_eq!(with_shw.class_for_bodyweight(kg("50")), class("59"));
+        assert_eq!(with_shw.class_for_bodyweight(kg("60.2")), class("66"));
+
+        // A bodyweight exactly on a limit belongs to that class.
+        assert_eq!(with_shw.class_for_bodyweight(kg("59")), class("59"));
+        assert_eq!(with_shw.class_for_bodyweight(kg("59.01")), class("66"));
+        assert_eq!(with_shw.class_for_bodyweight(kg("74")), class("74"));
+
+        // Heavier lifters are caught by the open class.
+        assert_eq!(with_shw.class_for_bodyweight(kg("74.01")), class("74+"));
+        assert_eq!(with_shw.class_for_bodyweight(kg("180")), class("74+"));
+
+        // Without an open class, overweight lifters have no class.
+        let without_shw = set(&["59", "66", "74"]);
+        assert_eq!(without_shw.class_for_bodyweight(kg("74.01")), None);
+
+        // An unknown bodyweight has no class.
+        assert_eq!(with_shw.class_for_bodyweight(WeightKg::from_i32(0)), None);
+    }
+
+    #[test]
+    fn exemption_folder_names() {
+        let count = |key: &str| {
+            let toml = format!(
+                "[divisions]\n[weightclasses]\n[exemptions]\n{key:?} = [\"ExemptLiftOrder\"]\n"
+            );
+            let (config, count) = parse_str(&toml);
+            let folders = config.map_or(0, |c| c.exemptions.len());
+            (count.errors(), count.warnings(), folders)
+        };
+
+        assert_eq!(count("1901"), (0, 0, 1));
+
+        // Empty and whitespace-only folders are dropped.
+        assert_eq!(count(""), (1, 0, 0));
+        assert_eq!(count("  "), (1, 0, 0));
+
+        // Paths can never match a meet folder.
+        assert_eq!(count("2019/1901"), (0, 1, 1));
+        assert_eq!(count("2019\\1901"), (0, 1, 1));
+    }
+
+    #[test]
+    fn identical_ladder_warnings() {
+        let set = |name: &str, sex: Sex, classes: &[&str], from: u32, to: u32| WeightClassConfig {
+            name: name.into(),
+            classes: classes.iter().map(|c| c.parse().unwrap()).collect(),
+            date_ranges: vec![(Date::from_parts(from, 01, 01), Date::from_parts(to, 12, 31))],
+            sex,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        };
+        let mens = ["59", "66", "74", "83", "93", "93+"];
+        let womens = ["47", "52", "57", "63", "72", "72+"];
+
+        // The Mx set is a copy of the men's set.
+        let config = config_from_parts(
+            vec![],
+            vec![
+                set("default_M", Sex::M, &mens, 2000, 2030),
+                set("default_F", Sex::F, &womens, 2000, 2030),
+                set("default_Mx", Sex::Mx, &mens, 2020, 2030),
+            ],
+        );
+        let all = config.identical_ladder_warnings();
+        assert_eq!(all.len(), 1);
+        assert!(all[0].contains("[weightclasses.default_M]"));
+        assert!(all[0].contains("[weightclasses.default_Mx]"));
+
+        // Sets in use at different times, or of the same sex, are fine.
+        let config = config_from_parts(
+            vec![],
+            vec![
+                set("old_M", Sex::M, &mens, 2000, 2009),
+                set("new_F", Sex::F, &mens, 2010, 2030),
+                set("new_M", Sex::M, &mens, 2010, 2030),
+            ],
+        );
+        assert_eq!(config.identical_ladder_warnings().len(), 1);
+    }
+
+    #[test]
+    fn sort_order() {
+        let toml = r#"
+            [divisions]
+            masters = { name = "Masters", min = 40, max = 999, sort_order = 3 }
+            open = { name = "Open", min = 0, max = 999, sort_order = 1 }
+            guest = { name = "Guest", min = 0, max = 999, place = "G" }
+            juniors = { name = "Juniors", min = 20, max = 23, sort_order = 2 }
+            bench = { name = "Bench Only", min = 0, max = 999 }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert_eq!(count.errors(), 0);
+        let config = config.unwrap();
+
+        // Ordered divisions come first, then the rest by name.
+        let names: Vec<&str> = config
+            .sorted_divisions_by_order()
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, ["Open", "Juniors", "Masters", "Bench Only", "Guest"]);
+
+        // Positions must be unique and non-negative.
+        let errors = |divisions: &str| {
+            let toml = format!("[divisions]\n{divisions}\n[weightclasses]\n[exemptions]\n");
+            parse_str(&toml).1.errors()
+        };
+        let duplicate = "a = { name = \"A\", min = 0, max = 999, sort_order = 1 }\n\
+                         b = { name = \"B\", min = 0, max = 999, sort_order = 1 }";
+        assert_eq!(errors(duplicate), 1);
+        assert_eq!(
+            errors("a = { name = \"A\", min = 0, max = 999, sort_order = -1 }"),
+            1
+        );
+        assert_eq!(
+            errors("a = { name = \"A\", min = 0, max = 999, sort_order = \"1\" }"),
+            1
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            juniors = { name = "Juniors", min = 20, max = 23 }
+            [aliases]
+            "Elite" = "Open"
+            "Junior" = "Juniors"
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert_eq!(count.errors(), 0);
+        let config = config.unwrap();
+        assert_eq!(config.division_by_name("Elite").unwrap().name, "Open");
+        assert_eq!(config.division_by_name("Junior").unwrap().name, "Juniors");
+        assert!(config.contains_division("Open"));
+        assert!(!config.contains_division("Masters"));
+
+        // Aliases must name a real division, and can't shadow one.
+        let errors = |aliases: &str| {
+            let toml = format!(
+                "[divisions]\nopen = {{ name = \"Open\", min = 0, max = 999 }}\n\
+                 [aliases]\n{aliases}\n[weightclasses]\n[exemptions]\n"
+            );
+            parse_str(&toml).1.errors()
+        };
+        assert_eq!(errors("\"Elite\" = \"Masters\""), 1);
+        assert_eq!(errors("\"Open\" = \"Open\""), 1);
+        assert_eq!(errors("\"Elite\" = 1"), 1);
+    }
+
+    #[test]
+    fn mixed_age_ranges() {
+        let errors = |min: &str, max: &str| {
+            let toml = format!(
+                "[divisions]\nd = {{ name = \"D\", min = {min}, max = {max} }}\n\
+                 [weightclasses]\n[exemptions]\n"
+            );
+            parse_str(&toml).1.errors()
+        };
+
+        // Exact and exact, meeting at the boundary.
+        assert_eq!(errors("40", "40"), 0);
+        assert_eq!(errors("40", "39"), 1);
+
+        // Approximate and approximate.
+        assert_eq!(errors("39.5", "39.5"), 0);
+        assert_eq!(errors("9.5", "10.5"), 0);
+        assert_eq!(errors("40.5", "39.5"), 1);
+
+        // Exact min, approximate max: 40 comes after "39 or 40".
+        assert_eq!(errors("40", "40.5"), 0);
+        assert_eq!(errors("40", "39.5"), 1);
+
+        // Approximate min, exact max.
+        assert_eq!(errors("39.5", "40"), 0);
+        assert_eq!(errors("40.5", "40"), 1);
+    }
+
+    #[test]
+    fn nonexistent_dates() {
+        let errors = |date_range: &str| {
+            let toml = format!(
+                "[divisions]\n[weightclasses]\n\
+                 m = {{ classes = [\"90\", \"90+\"], date_range = {date_range}, sex = \"M\" }}\n\
+                 [exemptions]\n"
+            );
+            parse_str(&toml).1.errors()
+        };
+        assert_eq!(errors(r#"["2000-02-29", "2019-12-31"]"#), 0);
+        assert_eq!(errors(r#"["2019-02-29", "2019-12-31"]"#), 1);
+        assert_eq!(errors(r#"["2019-01-01", "2019-04-31"]"#), 1);
+        assert_eq!(
+            errors(r#"[["1970-01-01", "1999-12-31"], ["2001-02-29", "9999-01-01"]]"#),
+            1
+        );
+
+        let valid_since = |date: &str| {
+            let toml = format!(
+                "[options]\nvalid_since = \"{date}\"\n[divisions]\n[weightclasses]\n[exemptions]\n"
+            );
+            parse_str(&toml).1.errors()
+        };
+        assert_eq!(valid_since("2020-02-29"), 0);
+        assert_eq!(valid_since("2021-02-29"), 1);
+    }
+
+    #[test]
+    fn weightclass_source() {
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            [weightclasses.default_M]
+            classes = ["59", "66", "74", "83", "93", "93+"]
+            date_range = ["2000-01-01", "9999-01-01"]
+            sex = "M"
+            source = "Official rulebook 2018 edition"
+            [weightclasses.default_F]
+            classes = ["47", "52", "57", "63", "72", "72+"]
+            date_range = ["2000-01-01", "9999-01-01"]
+            sex = "F"
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+        let source = |name: &str| {
+            let wc = config.weightclasses.iter().find(|wc| wc.name == name);
+            wc.unwrap().source.clone()
+        };
+        assert_eq!(
+            source("default_M").unwrap(),
+            "Official rulebook 2018 edition"
+        );
+        assert_eq!(source("default_F"), None);
+
+        // Both survive a round-trip.
+        let json = serde_json::to_string(&config).unwrap();
+        let reloaded: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, config);
+
+        // Only the set without a source is linted.
+        let warnings = config.weightclass_source_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("default_F"));
+
+        let bad = toml.replace("\"Official rulebook 2018 edition\"", "\"\"");
+        assert_eq!(parse_str(&bad).1.errors(), 1);
+    }
+
+    #[test]
+    fn coverage_report() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            juniors = { name = "Juniors", min = 19, max = 23 }
+            women = { name = "Women", min = 0, max = 999, sex = "F" }
+            [weightclasses]
+            old_M = { classes = ["90", "90+"], date_range = ["2000-01-01", "2010-12-31"], sex = "M" }
+            new_M = { classes = ["93", "93+"], date_range = ["2011-01-01", "9999-01-01"], sex = "M" }
+            juniors_M = { classes = ["83", "83+"], date_range = ["2011-01-01", "9999-01-01"], sex = "M", divisions = ["Juniors"] }
+            default_F = { classes = ["63", "63+"], date_range = ["2005-01-01", "9999-01-01"], sex = "F" }
+            [exemptions]
+        "#;
+        let config = parse_str(toml).0.unwrap();
+
+        // Juniors fall back to the default sets until they get their own,
+        // and Women don't appear in the men's table.
+        let expected = "\
+Sex: M
+Division  Ages   2000-01-01–2010-12-31  2011-01-01–present
+Juniors   19–23  old_M                  juniors_M
+Open      0+     old_M                  new_M
+
+Sex: F
+Division  Ages   2005-01-01–present
+Juniors   19–23  default_F
+Open      0+     default_F
+Women     0+     default_F
+";
+        assert_eq!(config.coverage_report(), expected);
+    }
+
+    #[test]
+    fn from_directory() {
+        let root = std::env::temp_dir().join(format!("checker_{}_from_dir", std::process::id()));
+        let fed = root.join("meet-data").join("uspa");
+        let meet = fed.join("1901").join("photos");
+        std::fs::create_dir_all(&meet).unwrap();
+        std::fs::write(meet.join("entries.csv"), "").unwrap();
+
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            [weightclasses]
+            [exemptions]
+        "#;
+        std::fs::write(fed.join("CONFIG.toml"), toml).unwrap();
+
+        // The CONFIG.toml two levels up is found from a file four levels down.
+        let config = Config::from_directory(&meet.join("entries.csv"))
+            .unwrap()
+            .unwrap();
+        assert!(config.division_by_name("Open").is_some());
+
+        // A CONFIG.toml with errors is reported rather than skipped.
+        std::fs::write(fed.join("CONFIG.toml"), "[divisions]\n").unwrap();
+        assert!(Config::from_directory(&meet).is_err());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn exemption_suggestions() {
+        let value = r#""1901" = ["ExemptLiftOrders", "exemptage", "Nonsense"]"#
+            .parse::<Value>()
+            .unwrap();
+        let mut report = Report::new(PathBuf::from("[inline]/CONFIG.toml"));
+        parse_exemptions(&value, &mut report);
+
+        let errors: Vec<&str> = report
+            .messages
+            .iter()
+            .filter_map(|m| match m {
+                Message::Error(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].ends_with("did you mean 'ExemptLiftOrder'?"));
+        assert!(errors[1].ends_with("did you mean 'ExemptAge'?"));
+        assert!(!errors[2].contains("did you mean"));
+    }
+
+    #[test]
+    fn division_level() {
+        let level = |value: &str| {
+            let toml = format!(
+                "[divisions]\nopen = {{ name = \"Open\", min = 0, max = 999{value} }}\n\
+                 [weightclasses]\n[exemptions]\n"
+            );
+            let (config, count) = parse_str(&toml);
+            (config.map(|c| c.divisions[0].level), count.errors())
+        };
+
+        for (name, expected) in [
+            ("Local", CompetitionLevel::Local),
+            ("Regional", CompetitionLevel::Regional),
+            ("National", CompetitionLevel::National),
+            ("International", CompetitionLevel::International),
+        ] {
+            let value = format!(", level = \"{name}\"");
+            assert_eq!(level(&value), (Some(Some(expected)), 0));
+        }
+
+        // The level is optional, but must be a known one.
+        assert_eq!(level(""), (Some(None), 0));
+        assert_eq!(level(", level = \"national\"").1, 1);
+        assert_eq!(level(", level = \"World\"").1, 1);
+        assert_eq!(level(", level = 1").1, 1);
+    }
+
+    #[test]
+    fn exemption_is_valid() {
+        let exemption = |folder: &str, exemptions: Vec<Exemption>| ExemptionConfig {
+            meet_folder: folder.to_string(),
+            exemptions,
+        };
+        assert!(exemption("1901", vec![Exemption::ExemptAge]).is_valid());
+        assert!(!exemption("1901", vec![]).is_valid());
+        assert!(!exemption("", vec![Exemption::ExemptAge]).is_valid());
+        assert!(!exemption("", vec![]).is_valid());
+
+        // Invalid exemptions are structural errors in programmatic configs.
+        let mut config = config_from_parts(vec![], vec![]);
+        config.exemptions = vec![exemption("1901", vec![]), exemption("1902", vec![])];
+        assert_eq!(config.sanity_check().len(), 2);
+
+        let repeated = exemption(
+            "1901",
+            vec![
+                Exemption::ExemptAge,
+                Exemption::ExemptDivision,
+                Exemption::ExemptAge,
+            ],
+        );
+        assert_eq!(
+            repeated.deduplicated(),
+            exemption(
+                "1901",
+                vec![Exemption::ExemptAge, Exemption::ExemptDivision]
+            )
+        );
+
+        // Parsing warns about both, keeping the deduplicated list.
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            [exemptions]
+            "1901" = []
+            "1902" = ["ExemptAge", "ExemptAge"]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert_eq!((count.errors(), count.warnings()), (0, 2));
+        assert_eq!(
+            config.unwrap().exemptions[1].exemptions,
+            vec![Exemption::ExemptAge]
+        );
+    }
+
+    #[test]
+    fn division_table_formats() {
+        let inline = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            juniors = { name = "Juniors", min = 19, max = 23, sex = "F", equipment = ["Raw"] }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let standard = r#"
+            [divisions.open]
+            name = "Open"
+            min = 0
+            max = 999
+
+            [divisions.juniors]
+            name = "Juniors"
+            min = 19
+            max = 23
+            sex = "F"
+            equipment = ["Raw"]
+
+            [weightclasses]
+            [exemptions]
+        "#;
+        let mixed = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+
+            [divisions.juniors]
+            name = "Juniors"
+            min = 19
+            max = 23
+            sex = "F"
+            equipment = ["Raw"]
+
+            [weightclasses]
+            [exemptions]
+        "#;
+
+        let (expected, count) = parse_str(inline);
+        assert_eq!(count.errors(), 0);
+        let expected = expected.unwrap();
+        assert_eq!(expected.divisions.len(), 2);
+        let expected_count = (count.errors(), count.warnings());
+        for toml in [standard, mixed] {
+            let (config, count) = parse_str(toml);
+            assert_eq!((count.errors(), count.warnings()), expected_count);
+            assert_eq!(config.unwrap(), expected);
+        }
+
+        // A division that isn't a table of either kind is an error.
+        let (_, count) = parse_str("[divisions]\nopen = \"Open\"\n[weightclasses]\n[exemptions]\n");
+        assert_eq!(count.errors(), 1);
+    }
+
+    #[test]
+    fn missing_sections() {
+        let sections = [
+            (
+                "divisions",
+                "[divisions]\nopen = { name = \"Open\", min = 0, max = 999 }\n",
+            ),
+            ("weightclasses", "[weightclasses]\n"),
+            ("exemptions", "[exemptions]\n"),
+        ];
+        let parse = |toml: &str, allow_missing_sections| {
+            let root = toml.parse::<Value>().unwrap();
+            let report = Report::new(PathBuf::from("[inline]/CONFIG.toml"));
+            let opts = ParseOptions {
+                allow_missing_sections,
+            };
+            let result = parse_config(&root, report, opts, &mut |_, _| ()).unwrap();
+            let count = result.report.count_messages();
+            (result.config, count.errors(), count.warnings())
+        };
+
+        for (missing, _) in sections {
+            let toml: String = sections
+                .iter()
+                .filter(|(name, _)| *name != missing)
+                .map(|(_, section)| *section)
+                .collect();
+
+            // By default, a missing section is fatal.
+            let (config, errors, _) = parse(&toml, false);
+            assert!(config.is_none(), "{missing}");
+            assert_eq!(errors, 1, "{missing}");
+
+            // Partial configs parse the section as empty, with a warning.
+            let (config, errors, warnings) = parse(&toml, true);
+            let config = config.unwrap();
+            assert_eq!((errors, warnings), (0, 1), "{missing}");
+            match missing {
+                "divisions" => assert!(config.divisions.is_empty()),
+                "weightclasses" => assert!(config.weightclasses.is_empty()),
+                _ => assert!(config.exemptions.is_empty()),
+            }
+        }
+    }
+
+    #[test]
+    fn birth_date_cutoffs() {
+        let toml = r#"
+            [divisions]
+            u18 = { name = "U18", born_after = "2006-12-31" }
+            cohort = { name = "2006 Cohort", born_after = "2005-12-31", born_before = "2007-01-01", min = 18, max = 999 }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert_eq!(count.errors(), 0);
+        let config = config.unwrap();
+        assert!(config.sanity_check().is_empty());
+
+        let u18 = config.division_by_name("U18").unwrap();
+        assert!(!u18.has_age_bounds());
+        assert!(!u18.birth_date_contains(Date::from_parts(2006, 12, 31)));
+        assert!(u18.birth_date_contains(Date::from_parts(2007, 1, 1)));
+
+        // Divisions are resolved by whichever bounds they specify.
+        let division = |age, birthdate: Option<(u32, u32, u32)>| {
+            let birthdate = birthdate.map(|(y, m, d)| Date::from_parts(y, m, d));
+            config
+                .division_for_lifter(Sex::M, Age::Exact(age), birthdate)
+                .map(|d| d.name.as_str())
+        };
+        assert_eq!(division(16, Some((2007, 5, 1))), Some("U18"));
+        assert_eq!(division(18, Some((2006, 3, 1))), Some("2006 Cohort"));
+        assert_eq!(division(17, Some((2006, 3, 1))), None);
+        assert_eq!(division(19, Some((2005, 3, 1))), None);
+
+        // Without a birth date, the cutoffs can't be met.
+        assert_eq!(division(16, None), None);
+        assert!(config.division_for(Sex::M, Age::Exact(16)).is_none());
+
+        let errors = |division: &str| {
+            let toml = format!("[divisions]\n{division}\n[weightclasses]\n[exemptions]\n");
+            parse_str(&toml).1.errors()
+        };
+        assert_eq!(
+            errors(r#"a = { name = "A", born_before = "2006-02-30" }"#),
+            1
+        );
+        assert_eq!(errors(r#"a = { name = "A", born_before = 2006 }"#), 1);
+        assert_eq!(
+            errors(r#"a = { name = "A", born_after = "2006-12-31", born_before = "2007-01-01" }"#),
+            1
+        );
+
+        // Either an age range or a birth-date cutoff is needed.
+        assert_eq!(errors(r#"a = { name = "A" }"#), 1);
+
+        // Programmatically-built divisions get the same message as parsed ones.
+        let nobody = DivisionConfig {
+            born_after: Some(Date::from_parts(2006, 12, 31)),
+            born_before: Some(Date::from_parts(2007, 1, 1)),
+            ..open_division("Nobody")
+        };
+        assert_eq!(
+            config_from_parts(vec![nobody], vec![]).sanity_check(),
+            [
+                "Division 'Nobody' is for lifters born after 2006-12-31 and before 2007-01-01, \
+              which admits nobody"
+            ]
+        );
+    }
+
+    #[test]
+    fn equipment_list_string() {
+        let division = |equipment| DivisionConfig {
+            equipment,
+            ..open_division("Open")
+        };
+        assert_eq!(division(None).equipment_list_string(), "Any");
+        assert_eq!(
+            division(Some(vec![Equipment::Raw])).equipment_list_string(),
+            "Raw"
+        );
+        assert_eq!(
+            division(Some(vec![
+                Equipment::Raw,
+                Equipment::Wraps,
+                Equipment::Single
+            ]))
+            .equipment_list_string(),
+            "Raw, Wraps, Single-ply"
+        );
+    }
+
+    #[test]
+    fn weightclass_accessors() {
+        let set = |classes: &[&str]| WeightClassConfig {
+            name: "default_M".to_string(),
+            classes: classes.iter().map(|c| c.parse().unwrap()).collect(),
+            date_ranges: vec![(
+                Date::from_parts(2000, 01, 01),
+                Date::from_parts(2030, 12, 31),
+            )],
+            sex: Sex::M,
+            divisions: None,
+            equipment: None,
+            provisional: vec![],
+            source: None,
+        };
+        let class = |s: &str| s.parse::<WeightClassKg>().unwrap();
+
+        let wc = set(&["59", "66", "74", "74+"]);
+        assert_eq!(wc.class_count(), 4);
+        assert!(wc.has_shw_class());
+        assert_eq!(wc.lightest_class(), Some(&class("59")));
+        assert_eq!(wc.heaviest_class(), Some(&class("74+")));
+
+        // Sets may end without a SHW class, as for some youth sets.
+        let wc = set(&["44", "48"]);
+        assert!(!wc.has_shw_class());
+        assert_eq!(wc.heaviest_class(), Some(&class("48")));
+
+        let wc = set(&[]);
+        assert_eq!(wc.class_count(), 0);
+        assert!(!wc.has_shw_class());
+        assert_eq!(wc.lightest_class(), None);
+        assert_eq!(wc.heaviest_class(), None);
+    }
+
+    #[test]
+    fn weightclass_floor() {
+        let warns = |sex, classes: &[&str]| {
+            let classes: Vec<WeightClassKg> = classes.iter().map(|c| c.parse().unwrap()).collect();
+            weightclass_floor_warning("test", sex, &classes).is_some()
+        };
+
+        // Typical sets start low enough.
+        assert!(!warns(
+            Sex::M,
+            &["59", "66", "74", "83", "93", "105", "120", "120+"]
+        ));
+        assert!(!warns(Sex::M, &["60", "67.5", "75", "75+"]));
+        assert!(!warns(Sex::F, &["47", "52", "57", "63", "72", "84", "84+"]));
+
+        // Truncated sets are warned about.
+        assert!(warns(Sex::M, &["67.5", "75", "82.5", "90", "90+"]));
+        assert!(warns(Sex::F, &["52", "57", "63", "63+"]));
+
+        // The + class doesn't count as the lightest class.
+        assert!(!warns(Sex::M, &["56+"]));
+        assert!(warns(Sex::M, &["67.5", "67.5+"]));
+
+        // There's no floor for Mx sets.
+        assert!(!warns(Sex::Mx, &["90", "90+"]));
+
+        // The check is opt-in, and skips sets restricted to divisions.
+        let toml = r#"
+            [divisions]
+            masters = { name = "Masters", min = 40, max = 999 }
+            [weightclasses]
+            [weightclasses.men]
+            classes = ["67.5", "75", "75+"]
+            date_range = ["2000-01-01", "2030-12-31"]
+            sex = "M"
+            [weightclasses.masters_men]
+            classes = ["67.5", "75", "75+"]
+            date_range = ["2000-01-01", "2030-12-31"]
+            sex = "M"
+            divisions = ["Masters"]
+            [exemptions]
+        "#;
+        let (config, report) = parse_str(toml);
+        assert!(!report.any());
+        let warnings = config.unwrap().heuristic_warnings();
+        let floors: Vec<&String> = warnings
+            .iter()
+            .filter(|w| w.contains("at the bottom"))
+            .collect();
+        assert_eq!(floors.len(), 1);
+        assert!(floors[0].contains("[weightclasses.men]"));
+    }
+
+    #[test]
+    fn division_name_characters() {
+        let division = |name: &str| {
+            let toml = format!(
+                "[divisions]\nd = {{ name = \"{name}\", min = 0, max = 999 }}\n\
+                 [weightclasses]\n[exemptions]\n"
+            );
+            parse_str(&toml).1
+        };
+
+        // Plain ASCII names are accepted silently.
+        assert!(!division("Masters 40-44").any());
+        assert!(!division("Juniors 18+").any());
+
+        // Accents and en-dashes produce a warning.
+        assert_eq!(division("Juniors 20\u{2013}23").warnings(), 1);
+        assert_eq!(division("Débutant").warnings(), 1);
+
+        assert_eq!(suggest_division_name("Masters 40-44"), None);
+        assert_eq!(
+            suggest_division_name("Juniors 20\u{2013}23").as_deref(),
+            Some("Juniors 20-23")
+        );
+        assert_eq!(
+            suggest_division_name("Débutant").as_deref(),
+            Some("Debutant")
+        );
+        assert_eq!(
+            suggest_division_name("Women\u{2019}s Open").as_deref(),
+            Some("Women's Open")
+        );
+
+        // Ordinary ASCII punctuation is fine.
+        assert!(!division("U/18").any());
+        assert!(!division("Masters (40-49)").any());
+
+        // Names without an ASCII spelling get no suggestion.
+        assert!(!division("Юниоры").any());
+        assert_eq!(suggest_division_name("Юниоры"), None);
+        assert_eq!(suggest_division_name("Open Юниоры"), None);
+    }
+
+    #[test]
+    fn exemption_applicability() {
+        // With divisions configured, every exemption can apply.
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            [weightclasses]
+            [exemptions]
+            1901 = ["ExemptWeightClassConsistency", "ExemptDivision", "ExemptAge"]
+        "#;
+        let (_, count) = parse_str(toml);
+        assert!(!count.any());
+
+        // Without divisions, neither division nor weightclass exemptions apply.
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            [exemptions]
+            1901 = ["ExemptWeightClassConsistency", "ExemptDivision", "ExemptLiftOrder"]
+        "#;
+        let (_, count) = parse_str(toml);
+        assert_eq!(count.errors(), 0);
+        assert_eq!(count.warnings(), 2);
+    }
+
+    #[test]
+    fn equipment_unlimited() {
+        let toml = r#"
+            [divisions]
+            array = { name = "Array Unlimited", min = 0, max = 999, equipment = ["Unlimited"] }
+            string = { name = "String Unlimited", min = 0, max = 999, equipment = "Unlimited" }
+            [weightclasses]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+
+        for division in config.unwrap().divisions {
+            assert_eq!(division.equipment, Some(vec![Equipment::Unlimited]));
+            let display = division.equipment.unwrap()[0].to_string();
+            assert_eq!(display.parse::<Equipment>(), Ok(Equipment::Unlimited));
+        }
+    }
+
+    #[test]
+    fn effective_weightclasses_for_entry() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            juniors = { name = "Juniors", min = 19, max = 23 }
+            [weightclasses]
+            [weightclasses.default_M]
+            classes = ["59", "66", "74", "83", "93", "93+"]
+            date_range = ["2000-01-01", "2009-12-31"]
+            sex = "M"
+            [weightclasses.juniors_M]
+            classes = ["53", "59", "66", "74", "83", "83+"]
+            date_range = ["2000-01-01", "2009-12-31"]
+            sex = "M"
+            divisions = ["Juniors"]
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+
+        let name = |sex, date, division| {
+            config
+                .effective_weightclasses_for_entry(sex, Equipment::Raw, date, division)
+                .map(|wc| wc.name.as_str())
+        };
+        let date = Date::from_parts(2005, 06, 01);
+
+        // Division-specific groups take priority.
+        assert_eq!(name(Sex::M, date, Some("Juniors")), Some("juniors_M"));
+
+        // Otherwise, fall back to the unrestricted group.
+        assert_eq!(name(Sex::M, date, Some("Open")), Some("default_M"));
+        assert_eq!(name(Sex::M, date, None), Some("default_M"));
+
+        // Date ranges are inclusive.
+        let first = Date::from_parts(2000, 01, 01);
+        let last = Date::from_parts(2009, 12, 31);
+        assert_eq!(name(Sex::M, first, Some("Juniors")), Some("juniors_M"));
+        assert_eq!(name(Sex::M, last, Some("Juniors")), Some("juniors_M"));
+        assert_eq!(name(Sex::M, Date::from_parts(2010, 01, 01), None), None);
+
+        // The sex must match.
+        assert_eq!(name(Sex::F, date, None), None);
+
+        // Only the most specific matches are listed, so a single match isn't ambiguous.
+        let names = |division| -> Vec<&str> {
+            config
+                .matching_weightclasses_for_entry(Sex::M, Equipment::Raw, date, division)
+                .iter()
+                .map(|wc| wc.name.as_str())
+                .collect()
+        };
+        assert_eq!(names(Some("Juniors")), ["juniors_M"]);
+        assert_eq!(names(Some("Open")), ["default_M"]);
+
+        // Overlapping groups that are equally specific are all listed.
+        let toml = r#"
+            [divisions]
+            [weightclasses]
+            old_M = { classes = ["90", "90+"], date_range = ["2000-01-01", "2009-12-31"], sex = "M" }
+            new_M = { classes = ["93", "93+"], date_range = ["2005-01-01", "2019-12-31"], sex = "M" }
+            [exemptions]
+        "#;
+        let config = parse_str(toml).0.unwrap();
+        let groups = config.matching_weightclasses_for_entry(Sex::M, Equipment::Raw, date, None);
+        let names: Vec<&str> = groups.iter().map(|wc| wc.name.as_str()).collect();
+        assert_eq!(names, ["new_M", "old_M"]);
+    }
+
+    #[test]
+    fn weightclasses_by_equipment() {
+        let toml = r#"
+            [divisions]
+            open = { name = "Open", min = 0, max = 999 }
+            [weightclasses]
+            [weightclasses.raw_M]
+            classes = ["59", "66", "74", "83", "93", "105", "120", "120+"]
+            date_range = ["2000-01-01", "2009-12-31"]
+            sex = "M"
+            equipment = ["Raw", "Wraps"]
+            [weightclasses.equipped_M]
+            classes = ["60", "67.5", "75", "82.5", "90", "100", "110", "125", "140", "140+"]
+            date_range = ["2000-01-01", "2009-12-31"]
+            sex = "M"
+            equipment = "Single-ply"
+            [exemptions]
+        "#;
+        let (config, count) = parse_str(toml);
+        assert!(!count.any());
+        let config = config.unwrap();
+
+        let name = |equipment| {
+            let date = Date::from_parts(2005, 06, 01);
+            config
+                .effective_weightclasses_for_entry(Sex::M, equipment, date, Some("Open"))
+                .map(|wc| wc.name.as_str())
+        };
+        assert_eq!(name(Equipment::Raw), Some("raw_M"));
+        assert_eq!(name(Equipment::Wraps), Some("raw_M"));
+        assert_eq!(name(Equipment::Single), Some("equipped_M"));
+        assert_eq!(name(Equipment::Multi), None);
+
+        // The restriction survives serialization.
+        let json = serde_json::to_string(&config).unwrap();
+        let reloaded: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, config);
+
+        // Invalid equipment is an error, as for divisions.
+        let (_, count) =
+            parse_str(&toml.replace(r#"equipment = "Single-ply""#, r#"equipment = "Jeans""#));
+        assert_eq!(count.errors(), 1);
+    }
 }
@@ -0,0 +1,189 @@
+//! Caches the results of checking CONFIG.toml files between runs.
+//!
+//! Each result is keyed by a hash of the file's contents, so only files that
+//! changed since the last run are checked. Warning baselines are applied
+//! by the caller afterwards, so they don't affect the cached result.
+
+use serde_derive::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::checklib::config::{check_config, federation_from_path, CheckResult, Config};
+use crate::Report;
+
+/// Identifies this build of the checker, for invalidating caches.
+///
+/// Any change to the checks or to the serialized form of a Config changes the
+/// binary, so its contents are hashed. The hash is computed once per process.
+pub fn checker_version() -> String {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION
+        .get_or_init(|| {
+            let binary = std::env::current_exe()
+                .and_then(fs::read)
+                .unwrap_or_default();
+            format!(
+                "{}-{:016x}",
+                env!("CARGO_PKG_VERSION"),
+                fxhash::hash64(&binary)
+            )
+        })
+        .clone()
+}
+
+/// A cached result, stored as JSON so that hits can be handed out by value.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResult {
+    /// Hash of the CONFIG.toml.
+    hash: u64,
+    /// The `(Report, Option<Config>)` produced by checking the file.
+    result: serde_json::Value,
+}
+
+/// Remembers `check_config()` results by file contents.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ConfigCache {
+    /// Identifies the checker that wrote the cache.
+    ///
+    /// Changes to the checks change their results, so a cache written by
+    /// a different checker is discarded.
+    version: String,
+    results: BTreeMap<PathBuf, CachedResult>,
+
+    #[serde(skip)]
+    hits: usize,
+    #[serde(skip)]
+    misses: usize,
+}
+
+impl ConfigCache {
+    /// Creates an empty cache for the given checker version.
+    pub fn new(version: &str) -> ConfigCache {
+        ConfigCache {
+            version: version.to_string(),
+            ..ConfigCache::default()
+        }
+    }
+
+    /// Loads the cache at `path`.
+    ///
+    /// A cache that is missing, malformed, or from another version is replaced
+    /// by an empty one, since it only ever saves time.
+    pub fn load(path: &Path, version: &str) -> ConfigCache {
+        let cache = fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<ConfigCache>(&s).ok());
+        match cache {
+            Some(cache) if cache.version == version => cache,
+            _ => ConfigCache::new(version),
+        }
+    }
+
+    /// Writes the cache to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Checks a CONFIG.toml like `check_config()`, reusing the cached result
+    /// if the file didn't change.
+    ///
+    /// Cached results have no `elapsed` time.
+    pub fn check_config(&mut self, config: PathBuf) -> Result<CheckResult, Box<dyn Error>> {
+        let contents = fs::read(&config)?;
+        let hash = fxhash::hash64(&contents);
+
+        if let Some(cached) = self.results.get(&config).filter(|c| c.hash == hash) {
+            let (report, cached_config): (Report, Option<Config>) =
+                serde_json::from_value(cached.result.clone())?;
+            self.hits += 1;
+            return Ok(CheckResult {
+                report,
+                config: cached_config,
+                elapsed: None,
+                federation: federation_from_path(&config),
+            });
+        }
+
+        let result = check_config(config.clone(), false)?;
+        let value = serde_json::to_value((&result.report, &result.config))?;
+        self.results.insert(
+            config,
+            CachedResult {
+                hash,
+                result: value,
+            },
+        );
+        self.misses += 1;
+        Ok(result)
+    }
+
+    /// The number of checks answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of checks that had to be run.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOML: &str = "[divisions]\n[weightclasses]\n[exemptions]\n";
+
+    #[test]
+    fn hits_and_misses() {
+        let dir = std::env::temp_dir().join(format!("checker_{}_cache", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("CONFIG.toml");
+        let cache_path = dir.join("config-cache.json");
+        fs::write(&config, TOML).unwrap();
+
+        // The first check misses, and the second hits with the same result.
+        let mut cache = ConfigCache::new("1");
+        let first = cache.check_config(config.clone()).unwrap();
+        let second = cache.check_config(config.clone()).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(first.config, second.config);
+        assert!(second.config.is_some());
+
+        // Changing the file misses.
+        fs::write(&config, format!("{TOML}\"1901\" = [\"ExemptAge\"]\n")).unwrap();
+        let changed = cache.check_config(config.clone()).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        assert_eq!(changed.config.unwrap().exemptions.len(), 1);
+
+        // Warning baselines are applied after checking, so adding one still hits.
+        let baseline = crate::checklib::config::warning_baseline_path(&config);
+        fs::write(&baseline, "warnings = []").unwrap();
+        cache.check_config(config.clone()).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (2, 2));
+
+        // A saved cache hits when loaded by the same version.
+        cache.save(&cache_path).unwrap();
+        let mut reloaded = ConfigCache::load(&cache_path, "1");
+        reloaded.check_config(config.clone()).unwrap();
+        assert_eq!((reloaded.hits(), reloaded.misses()), (1, 0));
+
+        // Another version starts over.
+        let mut other = ConfigCache::load(&cache_path, "2");
+        other.check_config(config.clone()).unwrap();
+        assert_eq!((other.hits(), other.misses()), (0, 1));
+
+        // So does a malformed cache.
+        fs::write(&cache_path, "{").unwrap();
+        let mut malformed = ConfigCache::load(&cache_path, "1");
+        malformed.check_config(config).unwrap();
+        assert_eq!((malformed.hits(), malformed.misses()), (0, 1));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -7,11 +7,12 @@ use strum::IntoEnumIterator;
 use unicode_normalization::UnicodeNormalization;
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::error::Error;
 use std::io;
 use std::path::PathBuf;
 
-use crate::checklib::config::{Config, Exemption, WeightClassConfig};
+use crate::checklib::config::{Config, Exemption, FourthAttemptPolicy, WeightClassConfig};
 use crate::checklib::lifterdata::LifterDataMap;
 use crate::checklib::meet::Meet;
 use crate::{EntryIndex, Report};
@@ -300,11 +301,53 @@ enum Header {
     School,
 }
 
+/// Columns that every entries.csv must have.
+const MANDATORY_COLUMNS: [Header; 6] = [
+    Header::Name,
+    Header::Sex,
+    Header::Equipment,
+    Header::TotalKg,
+    Header::Place,
+    Header::Event,
+];
+
+/// Columns that an entries.csv should have, if the data is available.
+const RECOMMENDED_COLUMNS: [Header; 4] = [
+    Header::Division,
+    Header::Age,
+    Header::BirthDate,
+    Header::Country,
+];
+
+/// Checks that no required columns are missing from the header row.
+///
+/// Missing mandatory columns are errors, while missing recommended columns
+/// are only warnings.
+pub fn check_column_completeness(headers: &[&str], report: &mut Report) {
+    let has = |column: Header| headers.iter().any(|h| h.parse::<Header>() == Ok(column));
+
+    for column in MANDATORY_COLUMNS {
+        if !has(column) {
+            report.error(format!("There must be a '{column}' column"));
+        }
+    }
+    if !has(Header::WeightClassKg) && !has(Header::BodyweightKg) {
+        report.error("There must be a 'BodyweightKg' or 'WeightClassKg' column");
+    }
+
+    for column in RECOMMENDED_COLUMNS {
+        if !has(column) {
+            report.warning(format!("There should be a '{column}' column"));
+        }
+    }
+}
+
 /// Checks that the headers are valid.
 fn check_headers(
     headers: &csv::StringRecord,
     meet: Option<&Meet>,
     config: Option<&Config>,
+    exempt_missing_event: bool,
     report: &mut Report,
 ) -> HeaderIndexMap {
     // Build a map of (Header -> index).
@@ -354,24 +397,23 @@ fn check_headers(
     }
 
     // Require mandatory columns.
-    {
-        use Header::*;
-        const MANDATORY_COLUMNS: [Header; 6] = [Name, Sex, Equipment, TotalKg, Place, Event];
-        for column in &MANDATORY_COLUMNS {
-            if !header_map.has(*column) {
-                report.error(format!("There must be a '{column}' column"));
-            }
+    for column in &MANDATORY_COLUMNS {
+        if *column == Header::Event && exempt_missing_event {
+            continue;
         }
-        if !header_map.has(Header::WeightClassKg) && !header_map.has(Header::BodyweightKg) {
-            report.error("There must be a 'BodyweightKg' or 'WeightClassKg' column");
+        if !header_map.has(*column) {
+            report.error(format!("There must be a '{column}' column"));
         }
     }
+    if !header_map.has(Header::WeightClassKg) && !header_map.has(Header::BodyweightKg) {
+        report.error("There must be a 'BodyweightKg' or 'WeightClassKg' column");
+    }
 
     // Configured federations must have standardized divisions,
     // and therefore must have a "Division" column.
     if let Some(config) = config {
         // But only if the configuration file actually specifies divisions!
-        if !header_map.has(Header::Division) && !config.divisions.is_empty() {
+        if !header_map.has(Header::Division) && !config.divisions().is_empty() {
             report.error("Configured federations require a 'Division' column");
         }
     }
@@ -391,6 +433,10 @@ fn check_headers(
     header_map
 }
 
+/// Checks a Name, which is written in "Firstname Lastname" order.
+///
+/// Names in "Lastname, Firstname" order can't occur: the CSV files are read
+/// without quoting, so the comma would split the name across two columns.
 fn check_column_name(name: &str, line: u64, report: &mut Report) -> CompactString {
     // Allow discarding disambiguation (everything after optional '#').
     let mut s = name;
@@ -660,11 +706,24 @@ fn check_column_birthdate(
     }
 }
 
+/// Guesses the canonical Sex for a non-canonical spelling, like "Male" or "f".
+fn suggest_sex(s: &str) -> Option<Sex> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "m" | "male" | "man" | "men" | "boy" | "boys" => Some(Sex::M),
+        "f" | "female" | "woman" | "women" | "w" | "girl" | "girls" => Some(Sex::F),
+        "mx" | "x" | "nb" | "nonbinary" | "non-binary" => Some(Sex::Mx),
+        _ => None,
+    }
+}
+
 fn check_column_sex(s: &str, line: u64, report: &mut Report) -> Sex {
     match s.parse::<Sex>() {
         Ok(s) => s,
         Err(_) => {
-            report.error_on(line, format!("Invalid Sex '{s}'"));
+            match suggest_sex(s) {
+                Some(sex) => report.error_on(line, format!("Invalid Sex '{s}': use '{sex}'")),
+                None => report.error_on(line, format!("Invalid Sex '{s}'")),
+            }
             Sex::default()
         }
     }
@@ -882,7 +941,17 @@ fn check_nonnegative_weight(s: &str, line: u64, header: Header, report: &mut Rep
     check_weight(s, line, header, report)
 }
 
-fn check_column_bodyweightkg(s: &str, line: u64, report: &mut Report) -> WeightKg {
+fn check_column_bodyweightkg(
+    s: &str,
+    exempt_zero: bool,
+    line: u64,
+    report: &mut Report,
+) -> WeightKg {
+    // Some meets used 0 as a sentinel for a missing bodyweight.
+    if exempt_zero && !s.is_empty() && s.parse::<WeightKg>().is_ok_and(WeightKg::is_zero) {
+        return WeightKg::from_i32(0);
+    }
+
     let weight = check_nonnegative_weight(s, line, Header::BodyweightKg, report);
     if weight != WeightKg::from_i32(0)
         && (weight < WeightKg::from_i32(15) || weight > WeightKg::from_i32(300))
@@ -940,12 +1009,12 @@ fn check_column_division(
     // Configuration files covering directories with results from
     // several federations, such as meet-data/plusa, can omit
     // the list of divisions to effectively cause full exemption.
-    if config.divisions.is_empty() {
+    if config.divisions().is_empty() {
         return;
     }
 
     // The division must appear in the configuration file.
-    if !config.divisions.iter().any(|d| d.name == s) {
+    if !config.contains_division(s) {
         report.error_on(line, format!("Unknown division '{s}'"));
     }
 }
@@ -958,7 +1027,10 @@ fn check_column_country(s: &str, line: u64, report: &mut Report) -> Option<Count
     match s.parse::<Country>() {
         Ok(c) => Some(c),
         Err(_) => {
-            report.error_on(line, format!("Unknown Country '{s}'"));
+            match Country::from_iso_code(s) {
+                Some(c) => report.error_on(line, format!("Unknown Country '{s}': use '{c}'")),
+                None => report.error_on(line, format!("Unknown Country '{s}'")),
+            }
             None
         }
     }
@@ -1013,6 +1085,243 @@ fn check_column_state(
     }
 }
 
+/// Describes each lift with non-zero data that isn't part of the entry's Event.
+fn unused_lift_errors(entry: &Entry) -> Vec<String> {
+    let event = entry.event;
+    let mut acc = vec![];
+    if entry.has_squat_data() && !event.has_squat() {
+        acc.push(format!("Event '{event}' cannot have squat data"));
+    }
+    if entry.has_bench_data() && !event.has_bench() {
+        acc.push(format!("Event '{event}' cannot have bench data"));
+    }
+    if entry.has_deadlift_data() && !event.has_deadlift() {
+        acc.push(format!("Event '{event}' cannot have deadlift data"));
+    }
+    acc
+}
+
+/// Checks that lifts outside of each entry's Event are zero or blank.
+///
+/// This is checked for each row by `do_check()`, so this is for entries
+/// that were produced some other way. Entries don't carry exemptions:
+/// callers should leave out entries from exempted meets.
+pub fn check_event_lift_consistency(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        for error in unused_lift_errors(entry) {
+            report.error(format!("{}: {error}", entry.name));
+        }
+    }
+}
+
+/// Warns about equipped lifters who have no lifts or total recorded.
+///
+/// These are usually lifters whose every lift was voided, but who were left
+/// in the results under their equipment. No-shows have no lifts by definition,
+/// and entries without a BodyweightKg are probably incomplete as a whole,
+/// so neither is reported. Raw and Straps lifters are not checked.
+///
+/// Results that only list disqualified lifters by name are common, so this
+/// isn't run by `do_check()`.
+pub fn check_equipment_lift_consistency(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        let equipped = matches!(
+            entry.equipment,
+            Equipment::Wraps | Equipment::Single | Equipment::Multi | Equipment::Unlimited
+        );
+        if !equipped || entry.place == Place::NS || entry.bodyweightkg.is_zero() {
+            continue;
+        }
+
+        let has_lifts = entry.has_squat_data()
+            || entry.has_bench_data()
+            || entry.has_deadlift_data()
+            || entry.totalkg.is_non_zero();
+        if !has_lifts {
+            report.warning(format!(
+                "{} competed in {} but has no lifts recorded",
+                entry.name, entry.equipment
+            ));
+        }
+    }
+}
+
+/// Checks that each TotalKg is at least each best lift that counts towards it.
+///
+/// Only the lifts in the entry's Event are compared, so a stray squat on a
+/// bench-only entry is left to `check_event_lift_consistency()`. Failed lifts
+/// are negative, so they never exceed the total.
+///
+/// `do_check()` already requires a total to be the sum of its best lifts,
+/// so this is for entries that were produced some other way.
+pub fn check_total_kg_above_opener(entries: &[Entry], report: &mut Report) {
+    for entry in entries.iter().filter(|e| e.totalkg > WeightKg::from_i32(0)) {
+        let lifts = [
+            ("Best3SquatKg", entry.event.has_squat(), entry.best3squatkg),
+            ("Best3BenchKg", entry.event.has_bench(), entry.best3benchkg),
+            (
+                "Best3DeadliftKg",
+                entry.event.has_deadlift(),
+                entry.best3deadliftkg,
+            ),
+        ];
+        for (header, in_event, best) in lifts {
+            if in_event && best > entry.totalkg {
+                report.error(format!(
+                    "{} has a TotalKg of {}, less than their {header} of {best}",
+                    entry.name, entry.totalkg
+                ));
+            }
+        }
+    }
+}
+
+/// Checks recorded fourth squat attempts against the federation's policy.
+///
+/// Federations declare the policy with `metadata.allow_fourth_attempts`.
+/// Under "Never", any Squat4Kg is an error. Under "WROnly", entries.csv can't
+/// say whether an attempt was for a record, but a record attempt must at least
+/// beat the lifter's best squat, so a lighter one is an error.
+pub fn check_squat4_requires_world_record(entries: &[Entry], config: &Config, report: &mut Report) {
+    let wr_only = match config.fourth_attempts {
+        FourthAttemptPolicy::Always => return,
+        FourthAttemptPolicy::Never => false,
+        FourthAttemptPolicy::WROnly => true,
+    };
+
+    for entry in entries.iter().filter(|e| e.squat4kg.is_non_zero()) {
+        if !wr_only {
+            report.error(format!(
+                "{} has a Squat4Kg, but the federation doesn't allow fourth attempts",
+                entry.name
+            ));
+        } else if entry.squat4kg.abs() <= entry.best3squatkg {
+            report.error(format!(
+                "{} has a Squat4Kg of {}, which doesn't beat their Best3SquatKg of {}, \
+                 but the federation only allows fourth attempts for world records",
+                entry.name, entry.squat4kg, entry.best3squatkg
+            ));
+        }
+    }
+}
+
+/// Wilks points below this are implausible for a completed entry.
+const MIN_PLAUSIBLE_WILKS: f64 = 20.0;
+
+/// Wilks points above this beat every known performance.
+const MAX_PLAUSIBLE_WILKS: f64 = 700.0;
+
+/// Warns about entries whose Wilks points are implausibly high or low.
+///
+/// Points aren't stored in entries.csv: they're calculated from the TotalKg
+/// and BodyweightKg when the database is compiled, so an implausible score
+/// usually means a typo in one of those. Entries without both are skipped.
+///
+/// Single-lift events and young lifters can legitimately score low, so this
+/// isn't run by `do_check()`.
+pub fn check_wilks_score_plausibility(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        if entry.totalkg <= WeightKg::from_i32(0) || entry.bodyweightkg.is_zero() {
+            continue;
+        }
+
+        let points = coefficients::wilks(entry.sex, entry.bodyweightkg, entry.totalkg);
+        if !(MIN_PLAUSIBLE_WILKS..=MAX_PLAUSIBLE_WILKS).contains(&f64::from(points)) {
+            report.warning(format!(
+                "{} has {points} Wilks from a TotalKg of {} at a BodyweightKg of {}: \
+                 is one of them a typo?",
+                entry.name, entry.totalkg, entry.bodyweightkg
+            ));
+        }
+    }
+}
+
+/// IPF GL Points below this are implausible for a completed entry.
+const MIN_PLAUSIBLE_GOODLIFT: f64 = 10.0;
+
+/// IPF GL Points above this beat every known performance.
+const MAX_PLAUSIBLE_GOODLIFT: f64 = 130.0;
+
+/// Warns about entries whose IPF GL Points are implausibly high or low.
+///
+/// Like Wilks, GL Points aren't stored in entries.csv, so this recomputes them
+/// with the published coefficients in `coefficients::goodlift()` and checks the
+/// result is in a plausible range. Unlike Wilks, the formula depends on the
+/// Equipment and Event, so it catches equipment typos too. Mx lifters are scored
+/// with the men's coefficients.
+///
+/// Entries the formula doesn't cover, like deadlift-only events or bodyweights
+/// under 35kg, are skipped, as are entries without a total or bodyweight.
+/// Like `check_wilks_score_plausibility()`, this isn't run by `do_check()`.
+pub fn check_goodlift_points_plausibility(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        if entry.totalkg <= WeightKg::from_i32(0) || entry.bodyweightkg.is_zero() {
+            continue;
+        }
+
+        let points = coefficients::goodlift(
+            entry.sex,
+            entry.equipment,
+            entry.event,
+            entry.bodyweightkg,
+            entry.totalkg,
+        );
+        if points == Points::from_i32(0) {
+            continue;
+        }
+        if !(MIN_PLAUSIBLE_GOODLIFT..=MAX_PLAUSIBLE_GOODLIFT).contains(&f64::from(points)) {
+            report.warning(format!(
+                "{} has {points} GL Points from a {} {} TotalKg of {} at a BodyweightKg of {}: \
+                 is one of them a typo?",
+                entry.name, entry.equipment, entry.event, entry.totalkg, entry.bodyweightkg
+            ));
+        }
+    }
+}
+
+/// How many rows to list for each missing field before summarizing the rest.
+const MISSING_FIELD_ROWS_SHOWN: usize = 10;
+
+/// A mandatory field, with a test for whether an entry is missing it.
+type RequiredField = (&'static str, fn(&Entry) -> bool);
+
+/// Checks that every entry has a value for each mandatory field.
+///
+/// Rows are numbered from 1 in the order given. Only the first few rows missing
+/// each field are listed, followed by a count of the rest.
+///
+/// A `Sex` or `Equipment` can't be missing once parsed, since `do_check()` reports
+/// empty values for those, so only the remaining fields are checked here.
+pub fn check_entries_have_required_fields(entries: &[Entry], report: &mut Report) {
+    let fields: [RequiredField; 3] = [
+        ("Name", |e| e.name.is_empty()),
+        ("Event", |e| e.event == Event::default()),
+        // Disqualified and no-show entries are marked by their Place instead.
+        ("TotalKg", |e| {
+            !e.place.is_dq() && e.place != Place::NS && e.totalkg.is_zero()
+        }),
+    ];
+
+    for (field, is_missing) in fields {
+        let rows: Vec<usize> = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| is_missing(e))
+            .map(|(i, _)| i + 1)
+            .collect();
+
+        for row in rows.iter().take(MISSING_FIELD_ROWS_SHOWN) {
+            report.error(format!("Row {row} is missing a value for '{field}'"));
+        }
+        if rows.len() > MISSING_FIELD_ROWS_SHOWN {
+            report.error(format!(
+                "{} more rows are missing a value for '{field}'",
+                rows.len() - MISSING_FIELD_ROWS_SHOWN
+            ));
+        }
+    }
+}
+
 fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Report) {
     let event = entry.event;
     let equipment = entry.equipment;
@@ -1021,14 +1330,8 @@ fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Repo
     let has_deadlift_data: bool = entry.has_deadlift_data();
 
     // Check that lift data isn't present outside of the specified Event.
-    if has_squat_data && !event.has_squat() {
-        report.error_on(line, format!("Event '{event}' cannot have squat data"));
-    }
-    if has_bench_data && !event.has_bench() {
-        report.error_on(line, format!("Event '{event}' cannot have bench data"));
-    }
-    if has_deadlift_data && !event.has_deadlift() {
-        report.error_on(line, format!("Event '{event}' cannot have deadlift data"));
+    for error in unused_lift_errors(entry) {
+        report.error_on(line, error);
     }
 
     // Check that the Equipment makes sense for the given Event.
@@ -1152,6 +1455,46 @@ fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Repo
     }
 }
 
+/// The least that the bar may be raised after a successful attempt.
+///
+/// This is the smallest increment allowed for record attempts.
+const MIN_ATTEMPT_INCREMENT: WeightKg = WeightKg::from_raw(50);
+
+/// Describes how an attempt fails to follow the heaviest attempt before it, if it does.
+///
+/// After a failure the weight may be repeated, but after a success it must be
+/// raised by at least `MIN_ATTEMPT_INCREMENT`.
+fn attempt_progression_error(
+    lift: &str,
+    attempt_num: u32,
+    maxweight: WeightKg,
+    attempt: WeightKg,
+) -> Option<String> {
+    // The bar weight shouldn't have lowered.
+    if attempt.abs() < maxweight.abs() {
+        return Some(format!(
+            "{lift}{attempt_num}Kg '{attempt}' lowered weight from '{maxweight}'"
+        ));
+    }
+
+    // A successful attempt shouldn't have been repeated.
+    if !maxweight.is_failed() && attempt.abs() == maxweight {
+        return Some(format!(
+            "{lift}{attempt_num}Kg '{attempt}' repeated a successful attempt"
+        ));
+    }
+
+    // Nor should it have been followed by an impossibly small increase.
+    if !maxweight.is_failed() && attempt.abs() < maxweight + MIN_ATTEMPT_INCREMENT {
+        return Some(format!(
+            "{lift}{attempt_num}Kg '{attempt}' is less than {MIN_ATTEMPT_INCREMENT}kg heavier \
+             than the successful '{maxweight}'"
+        ));
+    }
+
+    None
+}
+
 // Compares an attempt versus the current ascending weight.
 // Returns the new value for maxweight.
 fn process_attempt_pair(
@@ -1197,6 +1540,48 @@ fn process_attempt_pair(
     }
 }
 
+/// Checks that the first three attempts of each lift follow each other legally.
+///
+/// `do_check()` already reports lowered and repeated attempts for each row, but
+/// this also requires `MIN_ATTEMPT_INCREMENT` after a success, so it is stricter.
+/// Callers should leave out entries from meets with `Exemption::ExemptLiftOrder`.
+pub fn check_consistent_attempt_weight_increments(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        let lifts = [
+            ("Squat", [entry.squat1kg, entry.squat2kg, entry.squat3kg]),
+            ("Bench", [entry.bench1kg, entry.bench2kg, entry.bench3kg]),
+            (
+                "Deadlift",
+                [entry.deadlift1kg, entry.deadlift2kg, entry.deadlift3kg],
+            ),
+        ];
+
+        for (lift, attempts) in lifts {
+            let mut maxweight = WeightKg::from_i32(0);
+            for (attempt_num, attempt) in (1..).zip(attempts) {
+                if attempt.is_zero() {
+                    continue;
+                }
+                if maxweight.is_non_zero() {
+                    if let Some(error) =
+                        attempt_progression_error(lift, attempt_num, maxweight, attempt)
+                    {
+                        report.error(format!("{}: {error}", entry.name));
+                    }
+                }
+                if attempt.abs() >= maxweight.abs() {
+                    maxweight = attempt;
+                }
+            }
+        }
+    }
+}
+
+/// Checks one discipline's attempts against each other and against the Best3 column.
+///
+/// Failed attempts are negative. The Best3 value must be the heaviest successful
+/// attempt. If every recorded attempt failed, it must be zero or, as for DQs,
+/// the least failed attempt. Blank attempts are skipped.
 #[allow(clippy::too_many_arguments)]
 fn check_attempt_consistency_helper(
     lift: &str,
@@ -1393,7 +1778,7 @@ fn check_weightclass_consistency(
     // Configuration files covering directories with results from
     // several federations, such as meet-data/plusa, can omit
     // the list of divisions to effectively cause full exemption.
-    if config.map_or(false, |c| c.divisions.is_empty()) {
+    if config.map_or(false, |c| c.divisions().is_empty()) {
         return;
     }
 
@@ -1435,51 +1820,16 @@ fn check_weightclass_consistency(
 
     // Attempt to find out what weightclass group this row is a member of.
     //
-    // Groups are specified in an arbitrary order with date, sex, and division data.
-    // We want to find the one group that matches most closely.
-    let mut matched_group: Option<&WeightClassConfig> = None;
-    for group in &config.weightclasses {
-        // Sex and date information are mandatory and must match.
-        if date < group.date_min || date > group.date_max || entry.sex != group.sex {
-            continue;
-        }
-
-        // If there is a division qualifier, it must match.
-        let division_matches = match group.divisions {
-            Some(ref divs) => divs
-                .iter()
-                .any(|x| config.divisions[*x].name == entry.division),
-            None => true,
-        };
-
-        if !division_matches {
-            continue;
-        }
-
-        // Everything matches. Determine whether this group has a narrower scope
-        // than the currently best-known group.
-        if let Some(best) = matched_group {
-            // Ignore this group if it drops division information.
-            if best.divisions.is_some() && group.divisions.is_none() {
-                continue;
-            }
-
-            // Check if two things matched and we don't have reason to prefer
-            // one over the other! That's an error in the configuration file,
-            // so whine at the user and select an arbitrary group.
-            if best.divisions.is_some() == group.divisions.is_some() {
-                report.error_on(
-                    line,
-                    format!(
-                        "Matched both [weightclasses.{}] and [weightclasses.{}]",
-                        best.name, group.name
-                    ),
-                );
-            }
-        }
-
-        matched_group = Some(group);
+    // Groups that list the division are preferred over groups that don't.
+    // Several equally-specific groups matching is an error in the configuration
+    // file, so whine at the user and select an arbitrary group.
+    let division = Some(entry.division.as_str());
+    let groups =
+        config.matching_weightclasses_for_entry(entry.sex, entry.equipment, date, division);
+    if let [best, group, ..] = groups.as_slice() {
+        report.error_on(line, format!("Matched both {best} and {group}"));
     }
+    let matched_group: Option<&WeightClassConfig> = groups.first().copied();
 
     // If no group matched, the config is in trouble.
     if matched_group.is_none() {
@@ -1509,10 +1859,8 @@ fn check_weightclass_consistency(
         let suggestion: WeightClassKg = if entry.bodyweightkg.is_non_zero() {
             // Find a class that matches the bodyweight.
             matched_group
-                .classes
-                .iter()
-                .find(|w| w.matches_bodyweight(entry.bodyweightkg))
-                .map_or(WeightClassKg::None, |w| *w)
+                .class_for_bodyweight(entry.bodyweightkg)
+                .unwrap_or(WeightClassKg::None)
         } else if entry.weightclasskg.is_shw() {
             // Suggest any SHW weightclass.
             matched_group
@@ -1551,12 +1899,9 @@ fn check_weightclass_consistency(
             && matched_group.classes[index - 1].matches_bodyweight(entry.bodyweightkg)
         {
             // This is an error state, but we can calculate a more helpful message.
-            // Iterate over all of the classes in order and find the first one
-            // that matches.
+            // The previous class matches, so some class does.
             let first_match = matched_group
-                .classes
-                .iter()
-                .find(|c| c.matches_bodyweight(entry.bodyweightkg))
+                .class_for_bodyweight(entry.bodyweightkg)
                 .unwrap();
 
             report.error_on(
@@ -1675,18 +2020,20 @@ fn check_division_age_consistency(
     // Configuration files covering directories with results from
     // several federations, such as meet-data/plusa, can omit
     // the list of divisions to effectively cause full exemption.
-    if config.divisions.is_empty() {
+    if config.divisions().is_empty() {
         return (Age::None, Age::None);
     }
 
     // Division string errors are already handled by check_column_division().
-    let (min_age, max_age) = match config.divisions.iter().find(|d| d.name == entry.division) {
-        Some(div) => (div.min, div.max),
+    let division = match config.division_by_name(&entry.division) {
+        Some(div) => div,
         None => return (Age::None, Age::None),
     };
+    let (min_age, max_age) = (division.min, division.max);
 
-    // Use the various age-related columns to calculate a representative Age value.
-    let age = entry.age_on(meet_date);
+    // Use the various age-related columns to calculate a representative Age value,
+    // as the division counts it.
+    let age = division.effective_age(entry.age_on(meet_date));
 
     if age.is_definitely_less_than(min_age) {
         report.error_on(
@@ -1708,6 +2055,28 @@ fn check_division_age_consistency(
         );
     }
 
+    // Divisions with birth-date cutoffs can only be checked against a known BirthDate.
+    if let Some(birthdate) = entry.birthdate {
+        if let Some(after) = division.born_after.filter(|&after| birthdate <= after) {
+            report.error_on(
+                line,
+                format!(
+                    "BirthDate {birthdate} too early for division '{}': born after {after}",
+                    entry.division
+                ),
+            );
+        }
+        if let Some(before) = division.born_before.filter(|&before| birthdate >= before) {
+            report.error_on(
+                line,
+                format!(
+                    "BirthDate {birthdate} too late for division '{}': born before {before}",
+                    entry.division
+                ),
+            );
+        }
+    }
+
     // Handle specially the case of BirthYear-based age divisions.
     // The issue is that if we define a division that is 19.5-22.5 (IPF Juniors),
     // the "is_definitely" checks above will permit ages between 19-23,
@@ -1765,7 +2134,7 @@ fn check_division_sex_consistency(
     };
 
     // Get the configured sex for the division, or return if not specified.
-    let sex = match config.divisions.iter().find(|d| d.name == entry.division) {
+    let sex = match config.division_by_name(&entry.division) {
         Some(div) => match div.sex {
             Some(sex) => sex,
             None => return,
@@ -1801,7 +2170,7 @@ fn check_division_place_consistency(
     };
 
     // Get the configured place for the division, or return if not specified.
-    let place = match config.divisions.iter().find(|d| d.name == entry.division) {
+    let place = match config.division_by_name(&entry.division) {
         Some(div) => match div.place {
             Some(place) => place,
             None => return,
@@ -1840,12 +2209,13 @@ fn check_division_equipment_consistency(
         None => return,
     };
 
-    // Get the configured sex for the division, or return if not specified.
-    let eqlist = match config.divisions.iter().find(|d| d.name == entry.division) {
-        Some(div) => match &div.equipment {
-            Some(vec) => vec,
-            None => return,
-        },
+    // Get the configured equipment for the division, or return if not specified.
+    let division = match config.division_by_name(&entry.division) {
+        Some(div) => div,
+        None => return,
+    };
+    let eqlist = match &division.equipment {
+        Some(vec) => vec,
         None => return,
     };
 
@@ -1853,13 +2223,171 @@ fn check_division_equipment_consistency(
         report.error_on(
             line,
             format!(
-                "Division '{}' does not allow equipment '{}'",
-                entry.division, equipment
+                "Division '{}' only allows {}, not '{}'",
+                entry.division,
+                division.equipment_list_string(),
+                equipment
             ),
         );
     }
 }
 
+/// Checks that each Country existed on the date of the entry.
+///
+/// Unknown values, including ISO codes like "US", are already rejected when the
+/// Country column is parsed. Former countries like Yugoslavia are valid for
+/// historical meets, but are probably a mistake for later ones.
+pub fn check_country_format(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        let country = match entry.country {
+            Some(country) => country,
+            None => continue,
+        };
+        if let Some(year) = country.dissolved_year() {
+            if entry.entrydate.year() > year {
+                report.warning(format!(
+                    "{} has Country '{country}', which ceased to exist in {year}",
+                    entry.name
+                ));
+            }
+        }
+    }
+}
+
+/// Numeric places above this are probably typos, like "111" for "1".
+const MAX_PLAUSIBLE_PLACE: u8 = 100;
+
+/// Checks that the places make sense on their own.
+///
+/// Unknown values like "DNF" are already rejected when the Place column is
+/// parsed, so this only catches implausibly large numeric places.
+pub fn check_place_format(entries: &[Entry], report: &mut Report) {
+    for entry in entries {
+        if let Place::P(p) = entry.place {
+            if p.get() > MAX_PLAUSIBLE_PLACE {
+                report.warning(format!(
+                    "{} has Place {p}, which is implausibly large: is it a typo?",
+                    entry.name
+                ));
+            }
+        }
+    }
+}
+
+/// Warns about lifters whose rows in one meet are in different divisions.
+///
+/// Rows are grouped by name, sex, and equipment, as for a lifter entered twice
+/// in multi-ply data. Many meets let lifters enter several divisions, like Open
+/// and a masters division, so this isn't run by `do_check()`: it's for meets
+/// known to allow only one division per lifter.
+pub fn check_division_assignment_consistency(entries: &[Entry], report: &mut Report) {
+    let same_lifter =
+        |a: &Entry, b: &Entry| a.name == b.name && a.sex == b.sex && a.equipment == b.equipment;
+
+    // Groups are kept by their first row, in order of appearance for stable output.
+    let mut groups: Vec<(&Entry, Vec<&str>)> = vec![];
+    for entry in entries {
+        let divisions = match groups
+            .iter_mut()
+            .position(|(first, _)| same_lifter(first, entry))
+        {
+            Some(i) => &mut groups[i].1,
+            None => {
+                groups.push((entry, vec![]));
+                &mut groups.last_mut().unwrap().1
+            }
+        };
+        if !divisions.contains(&entry.division.as_str()) {
+            divisions.push(entry.division.as_str());
+        }
+    }
+
+    for (first, divisions) in groups {
+        if divisions.len() > 1 {
+            report.warning(format!(
+                "{} ({}, {}) is in more than one division: '{}'",
+                first.name,
+                first.sex,
+                first.equipment,
+                divisions.join("', '")
+            ));
+        }
+    }
+}
+
+/// Checks that the numeric places within each competition group follow TotalKg.
+///
+/// Entries compete against each other when they share a division, equipment,
+/// sex, weightclass, event, and testedness. Lifters with equal totals may share
+/// a place, in which case the following places are skipped, or they may be split
+/// by a tiebreaker. Entries without a numeric place are ignored.
+///
+/// Some federations place lifters by other criteria, like points, so this isn't
+/// run by `do_check()`.
+pub fn check_place_consistency(entries: &[Entry], report: &mut Report) {
+    type Group<'a> = (&'a str, Equipment, Sex, WeightClassKg, Event, bool);
+    fn group(e: &Entry) -> Group<'_> {
+        (
+            &e.division,
+            e.equipment,
+            e.sex,
+            e.weightclasskg,
+            e.event,
+            e.tested,
+        )
+    }
+
+    // The totals of each group, from greatest to least.
+    let mut totals: HashMap<Group, Vec<WeightKg>> = HashMap::new();
+    for entry in entries.iter().filter(|e| matches!(e.place, Place::P(_))) {
+        totals.entry(group(entry)).or_default().push(entry.totalkg);
+    }
+    for group_totals in totals.values_mut() {
+        group_totals.sort_unstable_by(|a, b| b.cmp(a));
+    }
+
+    for entry in entries {
+        let place = match entry.place {
+            Place::P(p) => usize::from(p.get()),
+            _ => continue,
+        };
+
+        // The range of places the entry can hold given the other totals.
+        let group_totals = &totals[&group(entry)];
+        let ahead = group_totals.partition_point(|&t| t > entry.totalkg);
+        let ahead_or_tied = group_totals.partition_point(|&t| t >= entry.totalkg);
+
+        if place <= ahead || place > ahead_or_tied {
+            let expected = if ahead + 1 == ahead_or_tied {
+                format!("{}", ahead + 1)
+            } else {
+                format!("{}-{}", ahead + 1, ahead_or_tied)
+            };
+            report.error(format!(
+                "{} placed {place} with TotalKg {}, but totals rank them {expected}",
+                entry.name, entry.totalkg
+            ));
+        }
+    }
+}
+
+/// Infers the Event from the lift data present in the Entry.
+///
+/// If there is no lift data, the Event is left empty.
+fn infer_event(entry: &Entry) -> Event {
+    let mut s = String::with_capacity(3);
+    if entry.has_squat_data() {
+        s.push('S');
+    }
+    if entry.has_bench_data() {
+        s.push('B');
+    }
+    if entry.has_deadlift_data() {
+        s.push('D');
+    }
+    s.parse::<Event>().unwrap_or_default()
+}
+
 /// Returns Testedness based on division configuration.
 fn tested_from_division_config(entry: &Entry, config: Option<&Config>) -> bool {
     let config = match config {
@@ -1869,7 +2397,7 @@ fn tested_from_division_config(entry: &Entry, config: Option<&Config>) -> bool {
         }
     };
 
-    match config.divisions.iter().find(|d| d.name == entry.division) {
+    match config.division_by_name(&entry.division) {
         Some(div) => match div.tested {
             Some(value) => value,
             None => entry.tested,
@@ -1917,24 +2445,22 @@ pub fn do_check<R: io::Read>(
         meet.map_or(false, |m| m.ruleset.contains(Rule::FourthAttemptsMayLower));
 
     // Scan for check exemptions.
-    let exemptions = {
-        let parent_folder = &report.parent_folder()?;
-        config.and_then(|c| c.exemptions_for(parent_folder))
-    };
-    let exempt_lift_order: bool = exemptions.map_or(false, |el| {
-        el.iter().any(|&e| e == Exemption::ExemptLiftOrder)
-    });
-    let exempt_division: bool = exemptions.map_or(false, |el| {
-        el.iter().any(|&e| e == Exemption::ExemptDivision)
-    });
-    let exempt_weightclass_consistency: bool = exemptions.map_or(false, |el| {
-        el.iter()
-            .any(|&e| e == Exemption::ExemptWeightClassConsistency)
-    });
-    let exempt_age: bool =
-        exemptions.map_or(false, |el| el.iter().any(|&e| e == Exemption::ExemptAge));
-
-    let headers: HeaderIndexMap = check_headers(rdr.headers()?, meet, config, &mut report);
+    let parent_folder = report.parent_folder()?;
+    let is_exempted = |e: Exemption| config.is_some_and(|c| c.is_exempted(parent_folder, e));
+    let exempt_lift_order: bool = is_exempted(Exemption::ExemptLiftOrder);
+    let exempt_division: bool = is_exempted(Exemption::ExemptDivision);
+    let exempt_weightclass_consistency: bool = is_exempted(Exemption::ExemptWeightClassConsistency);
+    let exempt_age: bool = is_exempted(Exemption::ExemptAge);
+    let exempt_missing_event: bool = is_exempted(Exemption::ExemptMissingEvent);
+    let exempt_bodyweight_zero: bool = is_exempted(Exemption::ExemptBodyweightZero);
+
+    let headers: HeaderIndexMap = check_headers(
+        rdr.headers()?,
+        meet,
+        config,
+        exempt_missing_event,
+        &mut report,
+    );
     if !report.messages.is_empty() {
         return Ok(EntriesCheckResult {
             report,
@@ -1945,6 +2471,12 @@ pub fn do_check<R: io::Read>(
 
     let mut entries: Vec<Entry> = Vec::new();
 
+    // Number of entries without an Event, for meets exempted from recording it.
+    let mut missing_event_count: usize = 0;
+
+    // Number of zero bodyweights treated as missing, for meets exempted from recording them.
+    let mut zero_bodyweight_count: usize = 0;
+
     // This allocation can be re-used for each row.
     let mut record = csv::StringRecord::new();
     while rdr.read_record(&mut record)? {
@@ -1990,7 +2522,9 @@ pub fn do_check<R: io::Read>(
             entry.age = check_column_age(&record[idx], exempt_age, line, &mut report);
         }
         if let Some(idx) = headers.get(Header::Event) {
-            entry.event = check_column_event(&record[idx], line, &headers, &mut report);
+            if !(exempt_missing_event && record[idx].is_empty()) {
+                entry.event = check_column_event(&record[idx], line, &headers, &mut report);
+            }
         }
 
         // Check all the weight fields: they must contain non-zero values.
@@ -2055,12 +2589,23 @@ pub fn do_check<R: io::Read>(
         }
 
         if let Some(idx) = headers.get(Header::BodyweightKg) {
-            entry.bodyweightkg = check_column_bodyweightkg(&record[idx], line, &mut report);
+            let s = &record[idx];
+            entry.bodyweightkg =
+                check_column_bodyweightkg(s, exempt_bodyweight_zero, line, &mut report);
+            if exempt_bodyweight_zero && !s.is_empty() && entry.bodyweightkg.is_zero() {
+                zero_bodyweight_count += 1;
+            }
         }
         if let Some(idx) = headers.get(Header::WeightClassKg) {
             entry.weightclasskg = check_column_weightclasskg(&record[idx], line, &mut report);
         }
 
+        // Meets exempted from recording the Event have it inferred from the lift data.
+        if exempt_missing_event && entry.event == Event::default() {
+            entry.event = infer_event(&entry);
+            missing_event_count += 1;
+        }
+
         // If no bodyweight is given but there is a bounded weightclass,
         // assume the pessimal case of the lifter at the top of the class.
         if entry.bodyweightkg.is_zero() {
@@ -2267,6 +2812,24 @@ pub fn do_check<R: io::Read>(
         entries.push(entry);
     }
 
+    check_country_format(&entries, &mut report);
+    check_place_format(&entries, &mut report);
+    if let Some(config) = config {
+        check_squat4_requires_world_record(&entries, config, &mut report);
+    }
+
+    if zero_bodyweight_count > 0 {
+        report.info(format!(
+            "{zero_bodyweight_count} entries have a BodyweightKg of 0 (ExemptBodyweightZero)"
+        ));
+    }
+
+    if missing_event_count > 0 {
+        report.info(format!(
+            "{missing_event_count} entries have no Event (ExemptMissingEvent)"
+        ));
+    }
+
     Ok(EntriesCheckResult {
         report,
         entries: Some(entries),
@@ -388,6 +388,10 @@ fn check_name_disambiguation(
     Ok(())
 }
 
+/// Checks the files in the lifter-data folder.
+///
+/// There is one report per file, even without any messages, so that callers
+/// can tell which files were checked.
 pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> LifterDataCheckResult {
     let mut reports: Vec<Report> = vec![];
     let mut map = LifterDataMap::with_hasher(FxBuildHasher::default());
@@ -401,9 +405,7 @@ pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> Lifter
             report.error(e);
         }
     }
-    if report.has_messages() {
-        reports.push(report)
-    }
+    reports.push(report);
 
     // Load exemptions from exemptions.toml.
     let mut report = Report::new(lifterdir.join("exemptions.toml"));
@@ -413,9 +415,7 @@ pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> Lifter
             report.error(e);
         }
     }
-    if report.has_messages() {
-        reports.push(report)
-    }
+    reports.push(report);
 
     // Check privacy.csv.
     let mut report = Report::new(lifterdir.join("privacy.csv"));
@@ -425,9 +425,7 @@ pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> Lifter
             report.error(e);
         }
     }
-    if report.has_messages() {
-        reports.push(report)
-    }
+    reports.push(report);
 
     // Check social-instagram.csv.
     let mut report = Report::new(lifterdir.join("social-instagram.csv"));
@@ -437,9 +435,7 @@ pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> Lifter
             report.error(e);
         }
     }
-    if report.has_messages() {
-        reports.push(report)
-    }
+    reports.push(report);
 
     // Check name-disambiguation.csv and produce a `HashMap<Username, Count>`.
     let mut report = Report::new(lifterdir.join("name-disambiguation.csv"));
@@ -449,9 +445,7 @@ pub fn check_lifterdata(reader: &csv::ReaderBuilder, lifterdir: &Path) -> Lifter
             report.error(e);
         }
     }
-    if report.has_messages() {
-        reports.push(report)
-    }
+    reports.push(report);
 
     LifterDataCheckResult { reports, map }
 }
@@ -133,14 +133,80 @@ pub fn check_federation(s: &str, report: &mut Report) -> Option<Federation> {
     }
 }
 
-/// Checks the Date column.
-pub fn check_date(s: &str, report: &mut Report) -> Option<Date> {
-    let date = s.parse::<Date>();
-    if date.is_err() {
-        report.error(format!("Invalid date '{s}'. Must be YYYY-MM-DD"));
+/// Parses a date written as three numbers separated by `sep`,
+/// with the day and month in the given order and a four-digit year last.
+fn parse_date_parts(s: &str, sep: char, month_first: bool) -> Option<Date> {
+    let parts: Vec<&str> = s.split(sep).collect();
+    if parts.len() != 3 || parts[2].len() != 4 {
         return None;
     }
-    let date = date.unwrap();
+    let a = parts[0].parse::<u32>().ok()?;
+    let b = parts[1].parse::<u32>().ok()?;
+    let year = parts[2].parse::<u32>().ok()?;
+    let (month, day) = if month_first { (a, b) } else { (b, a) };
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let date = Date::from_parts(year, month, day);
+    date.is_valid().then_some(date)
+}
+
+/// Checks that a date is in the ISO 8601 "YYYY-MM-DD" format.
+///
+/// Dates in common locale-specific formats are reported with a suggested
+/// correction. If the date can be read in only one way, that Date is returned;
+/// ambiguous dates like "05/04/2020" are reported for each distinct reading and
+/// return `None`. Formats that agree, like for "01/01/2020", are reported together.
+pub fn check_meet_date_format(s: &str, report: &mut Report) -> Option<Date> {
+    if let Ok(date) = s.parse::<Date>() {
+        return Some(date);
+    }
+
+    let alternatives = [
+        ("MM/DD/YYYY", parse_date_parts(s, '/', true)),
+        ("DD/MM/YYYY", parse_date_parts(s, '/', false)),
+        ("DD.MM.YYYY", parse_date_parts(s, '.', false)),
+    ];
+
+    // Group the formats by the date they read, keeping the order above.
+    let mut readings: Vec<(Date, Vec<&str>)> = vec![];
+    for (format, date) in alternatives {
+        let Some(date) = date else {
+            continue;
+        };
+        match readings.iter_mut().find(|(d, _)| *d == date) {
+            Some((_, formats)) => formats.push(format),
+            None => readings.push((date, vec![format])),
+        }
+    }
+
+    for (date, formats) in &readings {
+        let formats = formats.join(" or ");
+        report.error(format!(
+            "Date '{s}' looks like {formats}. Must be YYYY-MM-DD, like '{date}'"
+        ));
+    }
+
+    match readings.as_slice() {
+        [] => {
+            report.error(format!("Invalid date '{s}'. Must be YYYY-MM-DD"));
+            None
+        }
+        [(date, _)] => Some(*date),
+        _ => None,
+    }
+}
+
+/// Checks the Date column.
+pub fn check_date(s: &str, report: &mut Report) -> Option<Date> {
+    let date = match s.parse::<Date>() {
+        Ok(date) => date,
+        Err(_) => {
+            check_meet_date_format(s, report);
+            return None;
+        }
+    };
 
     // The date should not be implausibly long ago.
     if date.year() < 1945 {
@@ -285,6 +351,23 @@ pub fn check_meetname(s: &str, report: &mut Report, fedstr: &str, datestr: &str)
     Some(s.to_string())
 }
 
+/// Normalizes a federation name for comparison, ignoring case and hyphens.
+fn normalize_federation_name(s: &str) -> String {
+    s.replace('-', "").to_lowercase()
+}
+
+/// Checks that the meet's Federation matches the one named in the CONFIG.toml.
+pub fn check_federation_name_consistency(config: &Config, meet: &Meet, report: &mut Report) {
+    if let Some(expected) = config.federation() {
+        let federation = meet.federation.to_string();
+        if normalize_federation_name(&federation) != normalize_federation_name(expected) {
+            report.error(format!(
+                "Federation '{federation}' does not match the CONFIG.toml federation '{expected}'"
+            ));
+        }
+    }
+}
+
 /// Gets the default RuleSet for this meet.
 fn configured_ruleset(config: Option<&Config>, date: Option<Date>) -> RuleSet {
     // If there is incomplete specification, just use the defaults.
@@ -372,7 +455,7 @@ pub fn do_check<R: io::Read>(
 
     match (federation, date, country, name) {
         (Some(federation), Some(date), Some(country), Some(name)) => {
-            let meet = Some(Meet {
+            let meet = Meet {
                 path: meetpath,
                 federation,
                 date,
@@ -381,8 +464,14 @@ pub fn do_check<R: io::Read>(
                 town,
                 name,
                 ruleset,
-            });
-            Ok(MeetCheckResult { report, meet })
+            };
+            if let Some(config) = config {
+                check_federation_name_consistency(config, &meet, &mut report);
+            }
+            Ok(MeetCheckResult {
+                report,
+                meet: Some(meet),
+            })
         }
         _ => Ok(MeetCheckResult { report, meet: None }),
     }
@@ -2,10 +2,12 @@
 //! and applying data validation checks.
 
 pub mod config;
+pub mod config_cache;
 pub mod consistency;
 pub mod entries;
 pub mod lifterdata;
 pub mod meet;
+pub mod registry;
 
 pub use crate::Report;
 pub use entries::Entry;
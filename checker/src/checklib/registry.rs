@@ -0,0 +1,166 @@
+//! Collects the CONFIG.toml files of every federation in the data tree.
+
+use opltypes::Federation;
+use serde_derive::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::checklib::config::{check_config, Config};
+use crate::checklib::config_cache::checker_version;
+use crate::Report;
+
+/// Written at the start of a registry cache, to identify stale caches.
+///
+/// Like `ConfigCache`, this is stamped with the checker build, so a cache
+/// written by any other build is rejected instead of misread.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CacheHeader {
+    checker: String,
+}
+
+impl CacheHeader {
+    fn current() -> CacheHeader {
+        CacheHeader {
+            checker: checker_version(),
+        }
+    }
+}
+
+/// Maps each federation to its parsed CONFIG.toml.
+///
+/// Federations are found by matching the folder names directly under the
+/// meet-data root, like "meet-data/usapl", to a `Federation`. Folders that
+/// do not name a federation are ignored.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ConfigRegistry {
+    configs: BTreeMap<Federation, Config>,
+    /// Federation folders that have no CONFIG.toml.
+    missing: Vec<Federation>,
+    /// Federation folders whose CONFIG.toml failed to parse or had errors.
+    invalid: Vec<(Federation, Report)>,
+}
+
+impl ConfigRegistry {
+    /// Scans the meet-data root for federation folders and parses their configs.
+    pub fn from_meet_data(meet_data_root: &Path) -> Result<ConfigRegistry, Box<dyn Error>> {
+        let mut registry = ConfigRegistry::default();
+
+        let fed_iter = WalkDir::new(meet_data_root)
+            .min_depth(1)
+            .max_depth(1)
+            .sort_by_file_name();
+
+        for entry in fed_iter {
+            let entry = entry?;
+            if !entry.file_type().is_dir() {
+                continue;
+            }
+
+            let federation = match entry.file_name().to_str().map(str::parse::<Federation>) {
+                Some(Ok(federation)) => federation,
+                _ => continue,
+            };
+
+            let configpath = entry.path().join("CONFIG.toml");
+            if !configpath.is_file() {
+                registry.missing.push(federation);
+                continue;
+            }
+
+            match check_config(configpath.clone(), false) {
+                Ok(result) => match result.config {
+                    Some(config) if result.report.count_messages().errors() == 0 => {
+                        registry.configs.insert(federation, config);
+                    }
+                    _ => registry.invalid.push((federation, result.report)),
+                },
+                Err(e) => {
+                    let mut report = Report::new(configpath);
+                    report.error(e);
+                    registry.invalid.push((federation, report));
+                }
+            }
+        }
+
+        Ok(registry)
+    }
+
+    /// Writes the registry to `path` as a single bincode file.
+    ///
+    /// Loading it with `load_cache()` is much faster than parsing every CONFIG.toml.
+    pub fn save_cache(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut bytes = bincode::serialize(&CacheHeader::current())?;
+        bytes.extend(bincode::serialize(self)?);
+        fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Reads a registry written by `save_cache()`.
+    ///
+    /// Fails if the cache was written by a different build of the checker.
+    /// The cache is not compared against the CONFIG.toml files it came from,
+    /// so it must be rebuilt when they change.
+    pub fn load_cache(path: &Path) -> Result<ConfigRegistry, Box<dyn Error>> {
+        let bytes = fs::read(path)?;
+        let mut reader = bytes.as_slice();
+
+        let header: CacheHeader = bincode::deserialize_from(&mut reader)?;
+        if header != CacheHeader::current() {
+            let checker = header.checker;
+            return Err(format!(
+                "Stale registry cache '{}': written by checker {checker}",
+                path.display()
+            )
+            .into());
+        }
+
+        Ok(bincode::deserialize(reader)?)
+    }
+
+    /// Returns the Config for the given federation, if it has a valid one.
+    pub fn get(&self, federation: Federation) -> Option<&Config> {
+        self.configs.get(&federation)
+    }
+
+    /// Lints each federation's weightclasses against those of its parent federation.
+    ///
+    /// Only federations whose Config names a `parent_federation` with a valid
+    /// Config are checked. Returns each warning with the child federation.
+    pub fn parent_weightclass_warnings(&self) -> Vec<(Federation, String)> {
+        let mut acc = vec![];
+        for (&federation, config) in &self.configs {
+            let parent = match config.parent_federation.and_then(|p| self.get(p)) {
+                Some(parent) if config.parent_federation != Some(federation) => parent,
+                _ => continue,
+            };
+            for warning in config.parent_weightclass_warnings(parent) {
+                acc.push((federation, warning));
+            }
+        }
+        acc
+    }
+
+    /// Returns the number of federations with a valid Config.
+    pub fn len(&self) -> usize {
+        self.configs.len()
+    }
+
+    /// Whether no federation has a valid Config.
+    pub fn is_empty(&self) -> bool {
+        self.configs.is_empty()
+    }
+
+    /// Returns the federations whose folder has no CONFIG.toml.
+    pub fn missing(&self) -> &[Federation] {
+        &self.missing
+    }
+
+    /// Returns the federations whose CONFIG.toml is invalid, with the reasons.
+    pub fn invalid(&self) -> &[(Federation, Report)] {
+        &self.invalid
+    }
+}
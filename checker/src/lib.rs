@@ -10,7 +10,12 @@ extern crate serde_derive; // Provides struct serialization and deserialization.
 extern crate strum_macros; // Used for iterating over enums.
 
 pub mod checklib;
-pub use crate::checklib::config::{check_config, Config};
+pub use crate::checklib::config::{
+    apply_warning_baseline, check_config, check_config_with, check_config_with_options,
+    check_entries_against_config, check_exemption_folders, federation_from_path, Config, EntryLite,
+    ParseOptions, ValidateOptions,
+};
+pub use crate::checklib::config_cache::{checker_version, ConfigCache};
 pub use crate::checklib::consistency;
 pub use crate::checklib::entries::{
     check_entries, check_entries_from_string, EntriesCheckResult, Entry,
@@ -19,6 +24,7 @@ pub use crate::checklib::lifterdata::{
     check_lifterdata, LifterData, LifterDataCheckResult, LifterDataMap,
 };
 pub use crate::checklib::meet::{check_meet, check_meet_from_string, Meet, MeetCheckResult};
+pub use crate::checklib::registry::ConfigRegistry;
 pub use crate::checklib::CheckResult;
 
 pub mod compiler;
@@ -29,7 +35,7 @@ use meetdata::EntryIndex;
 pub use meetdata::{AllMeetData, LifterMap, SingleMeetData};
 
 mod report;
-pub use report::{Message, Report};
+pub use report::{diff_reports, offset_to_line_col, CheckSummary, JsonReport, Message, Report};
 
 pub mod report_count;
 
@@ -37,6 +43,9 @@ use std::error::Error;
 use std::path::Path;
 
 /// Checks a directory with meet data.
+///
+/// The reports for the meet.csv and entries.csv are always included, even
+/// without any messages, so that callers can tell which files were checked.
 pub fn check(
     reader: &csv::ReaderBuilder,
     meetdir: &Path,
@@ -47,9 +56,7 @@ pub fn check(
 
     // Check the meet.csv.
     let meetresult = check_meet(reader, meetdir.join("meet.csv"), config)?;
-    if !meetresult.report.messages.is_empty() {
-        acc.push(meetresult.report);
-    }
+    acc.push(meetresult.report);
 
     // Check the entries.csv.
     let entriesresult = check_entries(
@@ -59,9 +66,7 @@ pub fn check(
         config,
         lifterdata,
     )?;
-    if !entriesresult.report.messages.is_empty() {
-        acc.push(entriesresult.report);
-    }
+    acc.push(entriesresult.report);
 
     // Check for commonly-misnamed files.
     if meetdir.join("URL.txt").exists() {
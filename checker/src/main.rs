@@ -1,7 +1,8 @@
 //! Checks CSV data files for validity.
 
 use checker::report_count::ReportCount;
-use checker::{compiler, disambiguator, AllMeetData, SingleMeetData};
+use checker::{check_exemption_folders, compiler, disambiguator, AllMeetData, SingleMeetData};
+use checker::{diff_reports, CheckSummary, ConfigCache, JsonReport, Report};
 use colored::*;
 use opltypes::Username;
 use rayon::prelude::*;
@@ -16,7 +17,8 @@ use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::Instant;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "jemalloc")]
 #[global_allocator]
@@ -39,6 +41,24 @@ struct Args {
     /// Prints timing info for various phases of compilation or checking.
     debug_timing: bool,
 
+    /// Checks that every exemption refers to an existing meet folder.
+    audit_exemptions: bool,
+
+    /// Runs additional heuristic lints that may produce false positives.
+    verbose: bool,
+
+    /// Also writes every report to this file as JSON.
+    json: Option<PathBuf>,
+
+    /// Only reports errors that aren't in this JSON file, written by `--json`.
+    diff_against: Option<PathBuf>,
+
+    /// Prints aggregate statistics about every checked file at the end.
+    summary: bool,
+
+    /// Reuses CONFIG.toml results from this file for configs that didn't change.
+    config_cache: Option<PathBuf>,
+
     /// Whether the database should be compiled for the server.
     compile: bool,
 
@@ -85,10 +105,91 @@ fn write_report(handle: &mut io::StdoutLock, report: checker::Report) {
             checker::Message::Warning(s) => {
                 let _ = handle.write_fmt(format_args!(" {}\n", s.bold().yellow()));
             }
+            checker::Message::Info(s) => {
+                let _ = handle.write_fmt(format_args!(" {s}\n"));
+            }
         }
     }
 }
 
+/// Receives reports as they're produced.
+///
+/// Reports are normally printed immediately. For `--json`, `--diff-against`,
+/// and `--summary`, they're instead collected and handled together by `Sink::finish()`.
+///
+/// Reports without messages are never printed, but are collected so that
+/// the summary knows about every checked file.
+struct Sink {
+    collected: Option<Mutex<Vec<Report>>>,
+}
+
+impl Sink {
+    fn new(args: &Args) -> Self {
+        let collect = args.json.is_some() || args.diff_against.is_some() || args.summary;
+        Sink {
+            collected: collect.then(|| Mutex::new(vec![])),
+        }
+    }
+
+    /// Prints or collects a report after sorting and deduplicating its messages.
+    ///
+    /// Returns the count of messages that remain.
+    fn emit(&self, mut report: Report) -> ReportCount {
+        report.sort_and_dedup();
+        let count = report.count_messages();
+        match &self.collected {
+            Some(reports) => reports.lock().unwrap().push(report),
+            None if !report.has_messages() => (),
+            None => {
+                let stdout = io::stdout();
+                let mut handle = stdout.lock();
+                write_report(&mut handle, report);
+            }
+        }
+        count
+    }
+
+    /// Writes and prints any collected reports.
+    ///
+    /// Returns the count of messages that were printed, which for `--diff-against`
+    /// is only the new errors, or `count` if nothing was collected.
+    fn finish(self, args: &Args, count: ReportCount) -> Result<ReportCount, Box<dyn Error>> {
+        let mut reports = match self.collected {
+            Some(reports) => reports.into_inner().unwrap(),
+            None => return Ok(count),
+        };
+        // Meets are checked in parallel, so sort for stable output.
+        reports.sort_by(|a, b| a.path.cmp(&b.path));
+        let summary = args.summary.then(|| CheckSummary::from_reports(&reports));
+        reports.retain(Report::has_messages);
+        let current = JsonReport { reports };
+
+        if let Some(path) = &args.json {
+            fs::write(path, serde_json::to_string_pretty(&current)?)?;
+        }
+
+        let output = match &args.diff_against {
+            Some(path) => {
+                let baseline: JsonReport = serde_json::from_str(&fs::read_to_string(path)?)?;
+                diff_reports(&baseline, &current)
+            }
+            None => current,
+        };
+
+        let mut count = ReportCount::default();
+        let stdout = io::stdout();
+        let mut handle = stdout.lock();
+        for report in output.reports {
+            count += report.count_messages();
+            write_report(&mut handle, report);
+        }
+        if let Some(summary) = summary {
+            let _ = handle.write_fmt(format_args!("{summary}"));
+        }
+        Ok(count)
+    }
+}
+
 /// Outputs a final summary line.
 fn print_summary(report_count: ReportCount, search_root: &Path) {
     let error_count = report_count.errors();
@@ -132,9 +233,16 @@ type ConfigMap = BTreeMap<String, checker::Config>;
 
 /// Reads in all CONFIG.toml files project-wide.
 ///
+/// If `debug_timing` is set, the slowest CONFIG.toml to check is reported.
+/// The `audit_exemptions` and `verbose` flags enable additional checks.
+///
 /// Returns a map of (path -> Config) on success, or (errors, warnings) on
 /// failure.
-fn configurations(meet_data_root: &Path) -> Result<ConfigMap, ReportCount> {
+fn configurations(
+    meet_data_root: &Path,
+    args: &Args,
+    sink: &Sink,
+) -> Result<ConfigMap, ReportCount> {
     let mut configmap = ConfigMap::new();
 
     // Look at federation directories at depth 1, like "meet-data/usapl".
@@ -164,26 +272,53 @@ fn configurations(meet_data_root: &Path) -> Result<ConfigMap, ReportCount> {
         })
     });
 
-    let stdout = io::stdout();
-    let mut handle = stdout.lock();
-
     let mut overall_report_count = ReportCount::default();
 
+    // Results for unchanged configs can be reused from the last run.
+    // Hashing the binary is slow, so the version is only computed for a cache.
+    let mut cache = args
+        .config_cache
+        .as_ref()
+        .map(|path| ConfigCache::load(path, &checker::checker_version()));
+
+    // The CONFIG.toml that took the longest to check, if timing.
+    let mut slowest: Option<(PathBuf, Duration)> = None;
+
     // Parse each CONFIG.toml and file it in a hashmap.
     for configpath in configs {
         // Remember the filename for error reporting.
         let sourcefile: PathBuf = configpath.clone();
 
-        match checker::check_config(configpath) {
-            Ok(result) => {
-                // Tally up and output and errors and warnings.
-                let report_count = result.report.count_messages();
+        let result = match &mut cache {
+            Some(cache) => cache.check_config(configpath),
+            None => checker::check_config(configpath, args.debug_timing),
+        };
+        match result {
+            Ok(mut result) => {
+                if let Some(elapsed) = result.elapsed {
+                    if slowest.as_ref().is_none_or(|(_, d)| elapsed > *d) {
+                        slowest = Some((sourcefile.clone(), elapsed));
+                    }
+                }
+
+                if let (true, Some(config)) = (args.audit_exemptions, &result.config) {
+                    if let Some(config_dir) = sourcefile.parent() {
+                        check_exemption_folders(config, config_dir, &mut result.report);
+                    }
+                }
 
-                if report_count.any() {
-                    overall_report_count += report_count;
-                    write_report(&mut handle, result.report);
+                if let (true, Some(config)) = (args.verbose, &result.config) {
+                    for warning in config.heuristic_warnings() {
+                        result.report.warning(warning);
+                    }
                 }
 
+                // Only now has every warning been added.
+                checker::apply_warning_baseline(&mut result.report);
+
+                // Tally up and output and errors and warnings.
+                overall_report_count += sink.emit(result.report);
+
                 // Add the Config to the map.
                 if let Some(config) = result.config {
                     match opltypes::file_to_meetpath(&sourcefile) {
@@ -217,6 +352,20 @@ fn configurations(meet_data_root: &Path) -> Result<ConfigMap, ReportCount> {
         }
     }
 
+    if let (Some(cache), Some(path)) = (&cache, &args.config_cache) {
+        if let Err(e) = cache.save(path) {
+            println!(
+                " Couldn't save the config cache: {}",
+                e.to_string().bold().red()
+            );
+        }
+    }
+
+    if let Some((path, elapsed)) = slowest {
+        let pass = format!("Slowest configuration {}", path.to_str().unwrap());
+        print_elapsed(&pass, elapsed);
+    }
+
     // If there were errors, don't return anything.
     if overall_report_count.any() {
         Err(overall_report_count)
@@ -230,14 +379,19 @@ fn instant_if(b: bool) -> Option<Instant> {
     b.then(Instant::now)
 }
 
+/// Prints the elapsed time with the given prefix.
+fn print_elapsed(pass: &str, elapsed: Duration) {
+    let pass = pass.bold().cyan();
+    let elapsed_millis = elapsed.as_millis();
+    let whole_seconds = elapsed_millis / 1000;
+    let fractional_millis = elapsed_millis % 1000;
+    println!(" {pass}: {whole_seconds}.{fractional_millis:03}s");
+}
+
 /// Prints the elapsed time with the given prefix, if available.
 fn maybe_print_elapsed_for(pass: &str, instant: Option<Instant>) {
     if let Some(instant) = instant {
-        let pass = pass.bold().cyan();
-        let elapsed_millis = instant.elapsed().as_millis();
-        let whole_seconds = elapsed_millis / 1000;
-        let fractional_millis = elapsed_millis % 1000;
-        println!(" {pass}: {whole_seconds}.{fractional_millis:03}s");
+        print_elapsed(pass, instant.elapsed());
     }
 }
 
@@ -255,12 +409,18 @@ FLAGS:
     -c, --compile            Compiles the database into build/*.csv
     -1, --compile-onefile    Compiles build/openpowerlifting.csv, the easy-use variant
     -h, --help               Prints this help information
+    -v, --verbose            Also runs heuristic CONFIG.toml lints, like weightclass neighbors
 
 OPTIONS:
         --age <username>        Prints age debug info for the given username
         --age-group <username>  Prints disambugation age debug info for the given username
         --country <username>    Prints country debug info for the given username
         --timing                Prints timing information for compiler phases
+        --audit-exemptions      Checks that CONFIG.toml exemptions refer to existing meets
+        --json <file>           Also writes all errors and warnings to the file as JSON
+        --diff-against <file>   Only reports errors not already in a file written by --json
+        --summary               Prints statistics about every checked file at the end
+        --config-cache <file>   Only rechecks CONFIG.toml files that changed since the last run
 
 ARGS:
     <PATH>    Optionally restricts processing to just this parent directory
@@ -280,6 +440,12 @@ fn main() -> Result<(), Box<dyn Error>> {
         debug_age_group_username: args.opt_value_from_str("--age-group")?,
         debug_country_username: args.opt_value_from_str("--country")?,
         debug_timing: args.contains("--timing"),
+        audit_exemptions: args.contains("--audit-exemptions"),
+        verbose: args.contains(["-v", "--verbose"]),
+        json: args.opt_value_from_str("--json")?,
+        diff_against: args.opt_value_from_str("--diff-against")?,
+        summary: args.contains("--summary"),
+        config_cache: args.opt_value_from_str("--config-cache")?,
         compile: args.contains(["-c", "--compile"]),
         compile_onefile: args.contains(["-1", "--compile-onefile"]),
         free: args.finish(),
@@ -324,10 +490,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         || args.debug_age_group_username.is_some();
     let is_partial: bool = !search_root.ends_with("meet-data");
 
+    let sink = Sink::new(&args);
+
     let timing = instant_if(args.debug_timing);
-    let configmap = match configurations(&meet_data_root) {
+    let configmap = match configurations(&meet_data_root, &args, &sink) {
         Ok(configmap) => configmap,
         Err(report_count) => {
+            let report_count = sink.finish(&args, report_count)?;
             print_summary(report_count, &search_root);
             process::exit(1);
         }
@@ -348,7 +517,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     let timing = instant_if(args.debug_timing);
     let result = checker::check_lifterdata(&reader, &project_root.join("lifter-data"));
     for report in result.reports {
-        let report_count = report.count_messages();
+        // Pretty-print any messages.
+        let report_count = sink.emit(report);
         let errors = report_count.errors();
         let warnings = report_count.warnings();
 
@@ -359,13 +529,6 @@ fn main() -> Result<(), Box<dyn Error>> {
         if warnings > 0 {
             warning_count.fetch_add(warnings, Ordering::SeqCst);
         }
-
-        // Pretty-print any messages.
-        if report.has_messages() {
-            let stdout = io::stdout();
-            let mut handle = stdout.lock();
-            write_report(&mut handle, report);
-        }
     }
     let mut lifterdata = result.map;
     maybe_print_elapsed_for("Validated the state of `lifter-data`", timing);
@@ -390,12 +553,12 @@ fn main() -> Result<(), Box<dyn Error>> {
             // Check the meet.
             match checker::check(&reader, dir.path(), config, Some(&lifterdata)) {
                 Ok(checkresult) => {
-                    let reports = checkresult.reports;
-                    // Count how many new errors and warnings were generated.
+                    // Emit reports all together, counting how many new errors
+                    // and warnings were generated.
                     let mut local_errors = 0;
                     let mut local_warnings = 0;
-                    for report in &reports {
-                        let report_count = report.count_messages();
+                    for report in checkresult.reports {
+                        let report_count = sink.emit(report);
                         local_errors += report_count.errors();
                         local_warnings += report_count.warnings();
                     }
@@ -408,15 +571,6 @@ fn main() -> Result<(), Box<dyn Error>> {
                         warning_count.fetch_add(local_warnings, Ordering::SeqCst);
                     }
 
-                    // Emit reports all together.
-                    if local_errors > 0 || local_warnings > 0 {
-                        let stdout = io::stdout();
-                        let mut handle = stdout.lock();
-                        for report in reports {
-                            write_report(&mut handle, report);
-                        }
-                    }
-
                     // Map to the SingleMeetData for collection.
                     match (checkresult.meet, checkresult.entries) {
                         (Some(meet), Some(entries)) => Some(SingleMeetData { meet, entries }),
@@ -458,16 +612,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Check for consistency errors for individual lifters.
     let timing = instant_if(args.debug_timing);
     for report in checker::consistency::check(&liftermap, &meetdata, &lifterdata, is_partial) {
-        report_count += report.count_messages();
-
-        if report.has_messages() {
-            let stdout = io::stdout();
-            let mut handle = stdout.lock();
-            write_report(&mut handle, report);
-        }
+        report_count += sink.emit(report);
     }
     maybe_print_elapsed_for("Checked the data for consistency issues", timing);
 
+    // With --diff-against, only the new errors count.
+    let report_count = sink.finish(&args, report_count)?;
+
     // The default mode without arguments just performs data checks.
     print_summary(
         ReportCount::new(
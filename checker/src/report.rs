@@ -9,19 +9,47 @@
 //!    helps scan through them all.
 //! 3. The context itself is generally not helpful, because the data is CSV.
 
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
 
+use crate::checklib::CheckResult;
 use crate::report_count::ReportCount;
 
+/// Converts a byte offset into `src` to a 1-based (line, column) pair.
+///
+/// Columns count characters rather than bytes, so a multibyte character takes
+/// one column. Lines end at `\n` or `\r\n`, and an offset pointing at either
+/// part of a `\r\n` refers to the end of its line. Offsets past the end of `src`
+/// are clamped to it, and offsets inside a multibyte character refer to that character.
+pub fn offset_to_line_col(src: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(src.len());
+    while !src.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &src[..offset];
+    let line = before.bytes().filter(|&b| b == b'\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+    // The '\r' of a "\r\n" is part of the line ending, not a column.
+    let mut text = &before[line_start..];
+    if src[offset..].starts_with('\n') {
+        text = text.strip_suffix('\r').unwrap_or(text);
+    }
+    (line, text.chars().count() + 1)
+}
+
 /// A data error or warning message that should be reported.
-#[derive(Debug, Serialize)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Message {
     Error(String),
     Warning(String),
+    Info(String),
 }
 
 /// Accumulates messages that should be reported as a single batch.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Report {
     /// Each report represents errors/warnings from a single file. This is its path.
     pub path: PathBuf,
@@ -54,6 +82,11 @@ impl Report {
         self.messages.push(Message::Warning(message.to_string()));
     }
 
+    /// Reports an informational note, which does not affect whether checks pass.
+    pub fn info(&mut self, message: impl ToString) {
+        self.messages.push(Message::Info(message.to_string()));
+    }
+
     /// Reports a warning on a specific line.
     pub fn warning_on(&mut self, line: u64, message: impl ToString) {
         let msg = format!(" Line {line}: {}", message.to_string());
@@ -74,12 +107,36 @@ impl Report {
             match message {
                 Message::Error(_) => errors += 1,
                 Message::Warning(_) => warnings += 1,
+                Message::Info(_) => (),
             }
         }
 
         ReportCount::new(errors, warnings)
     }
 
+    /// Sorts the messages and removes exact duplicates, for stable output.
+    ///
+    /// Messages about the whole file come first, followed by messages about
+    /// specific lines in line order. Ties are broken by the message text,
+    /// then by errors before warnings before info.
+    pub fn sort_and_dedup(&mut self) {
+        fn key(message: &Message) -> (Option<u64>, &str, u8) {
+            let (text, rank) = match message {
+                Message::Error(s) => (s.as_str(), 0),
+                Message::Warning(s) => (s.as_str(), 1),
+                Message::Info(s) => (s.as_str(), 2),
+            };
+            let line = text
+                .strip_prefix(" Line ")
+                .and_then(|rest| rest.split_once(':'))
+                .and_then(|(n, _)| n.parse::<u64>().ok());
+            (line, text, rank)
+        }
+
+        self.messages.sort_by(|a, b| key(a).cmp(&key(b)));
+        self.messages.dedup();
+    }
+
     /// Returns the name of the parent folder of the given file.
     pub fn parent_folder(&self) -> Result<&str, &str> {
         self.path
@@ -89,3 +146,293 @@ impl Report {
             .ok_or("Insufficient parent directories")
     }
 }
+
+/// Every report from a run of the checker, as written by `--json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct JsonReport {
+    pub reports: Vec<Report>,
+}
+
+/// Returns just the errors in `current` that aren't in `baseline`.
+///
+/// Errors are matched by file and full message text, so an error that is
+/// reported twice in `current` but once in `baseline` is new once. Since the
+/// text includes the line number, adding rows can make later errors look new.
+///
+/// Warnings and info messages are not included, and reports left without
+/// any messages are dropped.
+pub fn diff_reports(baseline: &JsonReport, current: &JsonReport) -> JsonReport {
+    let mut known: HashMap<(&Path, &str), usize> = HashMap::new();
+    for report in &baseline.reports {
+        for message in &report.messages {
+            if let Message::Error(s) = message {
+                *known
+                    .entry((report.path.as_path(), s.as_str()))
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    let mut reports = vec![];
+    for report in &current.reports {
+        let mut diff = Report::new(report.path.clone());
+        for message in &report.messages {
+            if let Message::Error(s) = message {
+                match known.get_mut(&(report.path.as_path(), s.as_str())) {
+                    Some(count) if *count > 0 => *count -= 1,
+                    _ => diff.error(s),
+                }
+            }
+        }
+        if diff.has_messages() {
+            reports.push(diff);
+        }
+    }
+    JsonReport { reports }
+}
+
+/// Aggregate statistics about a run of the checker, as printed by `--summary`.
+#[derive(Debug, Default, PartialEq)]
+pub struct CheckSummary {
+    /// The number of distinct files with a report, with or without messages.
+    pub files_checked: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    /// The number of checked files without any errors.
+    pub files_without_errors: usize,
+    /// The file with the most errors and how many it has, if any file has errors.
+    ///
+    /// Ties go to the first file in path order.
+    pub most_errored_file: Option<(PathBuf, usize)>,
+    /// The number of errors by category, which is the file name, like `entries.csv`.
+    pub errors_by_category: BTreeMap<String, usize>,
+}
+
+impl CheckSummary {
+    /// Summarizes the reports of checked meet folders.
+    pub fn from_results(results: &[CheckResult]) -> Self {
+        Self::from_reports(results.iter().flat_map(|result| &result.reports))
+    }
+
+    /// Summarizes reports, which may include several reports about one file.
+    pub fn from_reports<'a>(reports: impl IntoIterator<Item = &'a Report>) -> Self {
+        let mut summary = CheckSummary::default();
+        let mut errors_by_file: BTreeMap<&Path, usize> = BTreeMap::new();
+
+        for report in reports {
+            let count = report.count_messages();
+            summary.errors += count.errors();
+            summary.warnings += count.warnings();
+            *errors_by_file.entry(report.path.as_path()).or_default() += count.errors();
+
+            if count.errors() > 0 {
+                let category = match report.path.file_name() {
+                    Some(name) => name.to_string_lossy().into_owned(),
+                    None => report.path.display().to_string(),
+                };
+                *summary.errors_by_category.entry(category).or_default() += count.errors();
+            }
+        }
+
+        summary.files_checked = errors_by_file.len();
+        summary.files_without_errors = errors_by_file.values().filter(|&&n| n == 0).count();
+        for (path, &errors) in &errors_by_file {
+            let most = summary.most_errored_file.as_ref().map_or(0, |(_, n)| *n);
+            if errors > most {
+                summary.most_errored_file = Some((path.to_path_buf(), errors));
+            }
+        }
+        summary
+    }
+}
+
+impl fmt::Display for CheckSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Check summary:")?;
+        writeln!(f, " Files checked:        {}", self.files_checked)?;
+        writeln!(f, " Files without errors: {}", self.files_without_errors)?;
+        writeln!(f, " Errors:               {}", self.errors)?;
+        writeln!(f, " Warnings:             {}", self.warnings)?;
+        if let Some((path, errors)) = &self.most_errored_file {
+            writeln!(f, " Most errors:          {} ({errors})", path.display())?;
+        }
+        for (category, errors) in &self.errors_by_category {
+            writeln!(f, " Errors in {category}: {errors}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(path: &str, errors: &[&str]) -> Report {
+        let mut report = Report::new(PathBuf::from(path));
+        for error in errors {
+            report.error(error);
+        }
+        report
+    }
+
+    fn errors(json: &JsonReport) -> Vec<(String, String)> {
+        let mut acc = vec![];
+        for report in &json.reports {
+            for message in &report.messages {
+                if let Message::Error(s) = message {
+                    acc.push((report.path.display().to_string(), s.clone()));
+                }
+            }
+        }
+        acc
+    }
+
+    #[test]
+    fn diff_reports_only_new_errors() {
+        let baseline = JsonReport {
+            reports: vec![report("uspa/1901/entries.csv", &["Old", "Twice"])],
+        };
+        let mut current = JsonReport {
+            reports: vec![
+                report("uspa/1901/entries.csv", &["Old", "New", "Twice", "Twice"]),
+                report("uspa/1902/entries.csv", &["Old"]),
+                report("uspa/1903/entries.csv", &[]),
+            ],
+        };
+        current.reports[0].warning("Warnings are never included");
+
+        // "Old" is known for 1901, but not for 1902.
+        let diff = diff_reports(&baseline, &current);
+        let expected = [
+            ("uspa/1901/entries.csv", "New"),
+            ("uspa/1901/entries.csv", "Twice"),
+            ("uspa/1902/entries.csv", "Old"),
+        ];
+        let expected: Vec<(String, String)> = expected
+            .iter()
+            .map(|(p, s)| (p.to_string(), s.to_string()))
+            .collect();
+        assert_eq!(errors(&diff), expected);
+        assert_eq!(diff.reports.len(), 2);
+
+        // Nothing is new compared to itself.
+        assert!(diff_reports(&current, &current).reports.is_empty());
+    }
+
+    #[test]
+    fn sort_and_dedup() {
+        let mut report = Report::new(PathBuf::from("uspa/1901/entries.csv"));
+        report.warning_on(10, "Late");
+        report.error_on(9, "Early");
+        report.error("Whole file");
+        report.error_on(10, "Late");
+        report.error_on(9, "Early");
+        report.info("A note");
+        report.warning_on(10, "Late");
+
+        report.sort_and_dedup();
+        let expected = [
+            Message::Info("A note".to_string()),
+            Message::Error("Whole file".to_string()),
+            Message::Error(" Line 9: Early".to_string()),
+            Message::Error(" Line 10: Late".to_string()),
+            Message::Warning(" Line 10: Late".to_string()),
+        ];
+        assert_eq!(report.messages, expected);
+    }
+
+    #[test]
+    fn check_summary() {
+        let mut meet = report("uspa/1901/meet.csv", &[]);
+        meet.warning("A warning");
+        let reports = [
+            meet,
+            report("uspa/1901/entries.csv", &["One"]),
+            report("uspa/1902/meet.csv", &[]),
+            report("uspa/1902/entries.csv", &["One", "Two"]),
+            report("uspa/1902/entries.csv", &["Three"]),
+        ];
+
+        let summary = CheckSummary::from_reports(&reports);
+        assert_eq!(summary.files_checked, 4);
+        assert_eq!(summary.files_without_errors, 2);
+        assert_eq!(summary.errors, 4);
+        assert_eq!(summary.warnings, 1);
+        let most = (PathBuf::from("uspa/1902/entries.csv"), 3);
+        assert_eq!(summary.most_errored_file, Some(most));
+        assert_eq!(summary.errors_by_category.get("entries.csv"), Some(&4));
+        assert_eq!(summary.errors_by_category.get("meet.csv"), None);
+
+        let text = summary.to_string();
+        assert!(text.contains("Files checked:        4"));
+        assert!(text.contains("Most errors:          uspa/1902/entries.csv (3)"));
+
+        // Without any errors, there's no most-errored file.
+        let clean = CheckSummary::from_reports(&reports[2..3]);
+        assert_eq!(clean.files_without_errors, 1);
+        assert_eq!(clean.most_errored_file, None);
+        assert!(clean.errors_by_category.is_empty());
+    }
+
+    #[test]
+    fn json_report_roundtrip() {
+        let json = JsonReport {
+            reports: vec![report("uspa/1901/entries.csv", &["Old"])],
+        };
+        let s = serde_json::to_string(&json).unwrap();
+        let parsed: JsonReport = serde_json::from_str(&s).unwrap();
+        assert_eq!(errors(&parsed), errors(&json));
+    }
+
+    #[test]
+    fn offset_to_line_col_basic() {
+        let src = "[divisions]\nopen = 1\n";
+        assert_eq!(offset_to_line_col(src, 0), (1, 1));
+        assert_eq!(offset_to_line_col(src, 1), (1, 2));
+        assert_eq!(offset_to_line_col(src, 11), (1, 12)); // The '\n'.
+        assert_eq!(offset_to_line_col(src, 12), (2, 1));
+        assert_eq!(offset_to_line_col(src, 19), (2, 8));
+    }
+
+    #[test]
+    fn offset_to_line_col_bounds() {
+        // Empty input only has the start.
+        assert_eq!(offset_to_line_col("", 0), (1, 1));
+        assert_eq!(offset_to_line_col("", 10), (1, 1));
+
+        // The offset just past the end is on the last line, after its text.
+        assert_eq!(offset_to_line_col("abc", 3), (1, 4));
+        assert_eq!(offset_to_line_col("abc\n", 4), (2, 1));
+
+        // Larger offsets are clamped to the end.
+        assert_eq!(offset_to_line_col("abc\nde", 100), (2, 3));
+    }
+
+    #[test]
+    fn offset_to_line_col_crlf() {
+        let src = "ab\r\ncd\r\n";
+        assert_eq!(offset_to_line_col(src, 2), (1, 3)); // The '\r'.
+        assert_eq!(offset_to_line_col(src, 3), (1, 3)); // The '\n'.
+        assert_eq!(offset_to_line_col(src, 4), (2, 1));
+        assert_eq!(offset_to_line_col(src, 5), (2, 2));
+        assert_eq!(offset_to_line_col(src, 8), (3, 1));
+
+        // A lone '\r' doesn't end a line.
+        assert_eq!(offset_to_line_col("a\rb", 2), (1, 3));
+    }
+
+    #[test]
+    fn offset_to_line_col_multibyte() {
+        // 'é' is 2 bytes and '💪' is 4 bytes, but each is one column.
+        let src = "é💪x\nñ";
+        assert_eq!(offset_to_line_col(src, 2), (1, 2));
+        assert_eq!(offset_to_line_col(src, 6), (1, 3));
+        assert_eq!(offset_to_line_col(src, 7), (1, 4));
+        assert_eq!(offset_to_line_col(src, 8), (2, 1));
+        assert_eq!(offset_to_line_col(src, 10), (2, 2));
+
+        // Offsets inside a character refer to that character.
+        assert_eq!(offset_to_line_col(src, 1), (1, 1));
+        assert_eq!(offset_to_line_col(src, 4), (1, 2));
+    }
+}
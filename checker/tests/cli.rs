@@ -0,0 +1,124 @@
+//! Tests for the command-line interface of the checker binary.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Creates a project tree with a single meet and empty lifter-data files.
+///
+/// The binary finds the project root from its own path, so it's copied
+/// into `target/release` of the new tree.
+fn make_project(name: &str, entries_csv: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("checker_cli_{}_{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    let release = root.join("target").join("release");
+    fs::create_dir_all(&release).unwrap();
+    fs::copy(env!("CARGO_BIN_EXE_checker"), release.join("checker")).unwrap();
+
+    let lifterdata = root.join("lifter-data");
+    fs::create_dir_all(&lifterdata).unwrap();
+    let files = [
+        ("donator-colors.csv", "Name,Color\n"),
+        (
+            "exemptions.toml",
+            "[sex]\nusernames = []\n[bodyweight]\nusernames = []\n",
+        ),
+        ("privacy.csv", "Name\n"),
+        ("social-instagram.csv", "Name,Instagram\n"),
+        ("name-disambiguation.csv", "Name,Count\n"),
+    ];
+    for (file, contents) in files {
+        fs::write(lifterdata.join(file), contents).unwrap();
+    }
+
+    let meet = root.join("meet-data").join("uspa").join("1901");
+    fs::create_dir_all(&meet).unwrap();
+    let meet_csv = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                    USPA,2019-01-05,USA,CA,Fresno,Winter Open\n";
+    fs::write(meet.join("meet.csv"), meet_csv).unwrap();
+    fs::write(meet.join("entries.csv"), entries_csv).unwrap();
+    root
+}
+
+fn run(root: &Path, args: &[&str]) -> Output {
+    let binary = root.join("target").join("release").join("checker");
+    Command::new(binary).args(args).output().unwrap()
+}
+
+const HEADER: &str = "Name,Sex,Event,Equipment,Division,WeightClassKg,\
+                      Best3SquatKg,Best3BenchKg,Best3DeadliftKg,TotalKg,Place\n";
+
+#[test]
+fn summary_flag() {
+    let entries = format!("{HEADER}Test User,M,SBD,Raw,Open,90,200,150,250,600,1\n");
+    let root = make_project("summary_flag", &entries);
+
+    // Without the flag, only the final line is printed.
+    let output = run(&root, &[]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{stdout}");
+    assert!(!stdout.contains("Check summary"));
+
+    // Files without messages are still counted.
+    let output = run(&root, &["--summary"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("Check summary:"));
+    assert!(stdout.contains("Files checked:        7"));
+    assert!(stdout.contains("Files without errors: 7"));
+    assert!(!stdout.contains("Most errors"));
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn summary_flag_with_errors() {
+    let entries = format!("{HEADER}Test User,M,SBD,Raw,Open,90,200,150,250,601,1\n");
+    let root = make_project("summary_flag_with_errors", &entries);
+
+    let output = run(&root, &["--summary"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success(), "{stdout}");
+    assert!(stdout.contains("Files without errors: 6"));
+    assert!(stdout.contains("Most errors:"));
+    assert!(stdout.contains("Errors in entries.csv: 1"));
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn verbose_warnings_can_be_baselined() {
+    let entries = format!("{HEADER}Test User,M,SBD,Raw,Open,90,200,150,250,600,1\n");
+    let root = make_project("verbose_warnings_can_be_baselined", &entries);
+
+    // A set starting at 90kg produces heuristic warnings, but only with --verbose.
+    let config = root.join("meet-data").join("uspa").join("CONFIG.toml");
+    let toml = r#"
+        [divisions]
+        open = { name = "Open", min = 0, max = 999 }
+        [weightclasses]
+        default_M = { classes = ["90", "90+"], date_range = ["2000-01-01", "2030-12-31"], sex = "M" }
+        [exemptions]
+    "#;
+    fs::write(&config, toml).unwrap();
+    let output = run(&root, &[]);
+    assert!(output.status.success(), "{output:?}");
+    let output = run(&root, &["--verbose"]);
+    assert!(!output.status.success(), "{output:?}");
+
+    // Those warnings are matched by the baseline like any other.
+    let result = checker::check_config(config.clone(), false).unwrap();
+    let warnings = result.config.unwrap().heuristic_warnings();
+    assert!(!warnings.is_empty());
+    let baseline = format!("warnings = {warnings:?}");
+    fs::write(
+        config.with_file_name("CONFIG.warnings-baseline.toml"),
+        baseline,
+    )
+    .unwrap();
+    let output = run(&root, &["--verbose"]);
+    assert!(output.status.success(), "{output:?}");
+
+    let _ = fs::remove_dir_all(&root);
+}
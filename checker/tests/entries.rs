@@ -3,20 +3,74 @@
 extern crate checker;
 extern crate csv;
 
-use checker::checklib::entries::do_check;
-use checker::Report;
+use checker::checklib::entries::{
+    check_column_completeness, check_consistent_attempt_weight_increments, check_country_format,
+    check_division_assignment_consistency, check_entries_have_required_fields,
+    check_equipment_lift_consistency, check_event_lift_consistency,
+    check_goodlift_points_plausibility, check_place_consistency, check_place_format,
+    check_total_kg_above_opener, check_wilks_score_plausibility, do_check, Entry,
+};
+use checker::checklib::meet::Meet;
+use checker::{check_config, Message, Report};
+use opltypes::{Country, Date, Event, Federation, Place, RuleSet, WeightKg};
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Executes checks against a string representation of a CSV,
+/// returning the resulting Report.
+fn check_report(csv: &str) -> Report {
+    let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+    let mut rdr = csv::ReaderBuilder::new()
+        .quoting(false)
+        .from_reader(csv.as_bytes());
+    let checkresult = do_check(&mut rdr, None, None, None, report).unwrap();
+    checkresult.report
+}
 
 /// Executes checks against a string representation of a CSV,
 /// returning the number of errors.
 fn check(csv: &str) -> usize {
+    check_report(csv).count_messages().errors()
+}
+
+/// Numbers the CONFIG.toml files written by concurrently-running tests.
+static CONFIG_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Executes checks against a CSV with the given CONFIG.toml contents,
+/// returning the resulting Report.
+fn check_with_config_report(csv: &str, config_toml: &str) -> Report {
+    let n = CONFIG_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let filename = format!("checker_{}_{n}.toml", std::process::id());
+    let configpath = std::env::temp_dir().join(filename);
+    std::fs::write(&configpath, config_toml).unwrap();
+    let config = check_config(configpath.clone(), false)
+        .unwrap()
+        .config
+        .unwrap();
+    std::fs::remove_file(configpath).unwrap();
+
     let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
     let mut rdr = csv::ReaderBuilder::new()
         .quoting(false)
         .from_reader(csv.as_bytes());
-    let checkresult = do_check(&mut rdr, None, None, None, report).unwrap();
-    checkresult.report.count_messages().errors()
+    let checkresult = do_check(&mut rdr, None, Some(&config), None, report).unwrap();
+    checkresult.report
+}
+
+/// Executes checks against a CSV with the given CONFIG.toml contents,
+/// returning the number of (errors, warnings).
+fn check_with_config(csv: &str, config_toml: &str) -> (usize, usize) {
+    let count = check_with_config_report(csv, config_toml).count_messages();
+    (count.errors(), count.warnings())
+}
+
+/// Checks a header row for completeness, returning (errors, warnings).
+fn check_completeness(headers: &[&str]) -> (usize, usize) {
+    let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+    check_column_completeness(headers, &mut report);
+    let count = report.count_messages();
+    (count.errors(), count.warnings())
 }
 
 #[test]
@@ -102,6 +156,52 @@ fn test_column_sex() {
     let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
                 Test User,90,Z,100,100,Raw,B,1";
     assert_eq!(check(data), 1);
+
+    let errors = |sex: &str| -> Vec<String> {
+        let data = format!(
+            "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+             Test User,90,{sex},100,100,Raw,B,1"
+        );
+        let report = check_report(&data);
+        let mut acc = vec![];
+        for message in report.messages {
+            if let Message::Error(s) = message {
+                acc.push(s);
+            }
+        }
+        acc
+    };
+
+    // Each canonical value is accepted.
+    for sex in ["M", "F", "Mx"] {
+        assert!(errors(sex).is_empty(), "{sex}");
+    }
+
+    // Clear but non-canonical values suggest the canonical one.
+    for (sex, canonical) in [("Male", "M"), ("female", "F"), ("m", "M"), ("MX", "Mx")] {
+        let expected = format!(" Line 2: Invalid Sex '{sex}': use '{canonical}'");
+        assert_eq!(errors(sex), [expected]);
+    }
+
+    // Anything else has no suggestion.
+    assert_eq!(errors("Alien"), [" Line 2: Invalid Sex 'Alien'"]);
+}
+
+#[test]
+fn test_column_name_order() {
+    // Names are written "Firstname Lastname".
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,100,100,Raw,B,1";
+    assert_eq!(check(data), 0);
+
+    // "Lastname, Firstname" splits into an extra field, so the file can't be read.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                User, Test,90,M,100,100,Raw,B,1";
+    let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+    let mut rdr = csv::ReaderBuilder::new()
+        .quoting(false)
+        .from_reader(data.as_bytes());
+    assert!(do_check(&mut rdr, None, None, None, report).is_err());
 }
 
 #[test]
@@ -198,3 +298,712 @@ fn regression_tests() {
                 Aleksey Krasnoshchekov,M,86,,,,,,170,90,SBD,Raw,No,-135,-135,-135,,,160,165,170,170,,Pro Masters 40-44,42,1";
     assert_eq!(check(data), 1);
 }
+
+#[test]
+fn test_column_completeness() {
+    // Every mandatory and recommended column is present.
+    let headers = [
+        "Name",
+        "Sex",
+        "Age",
+        "BirthDate",
+        "Country",
+        "Division",
+        "BodyweightKg",
+        "Equipment",
+        "Event",
+        "TotalKg",
+        "Place",
+    ];
+    assert_eq!(check_completeness(&headers), (0, 0));
+
+    // Missing Sex is an error, and missing Age and Country are warnings.
+    let headers = [
+        "Name",
+        "BirthDate",
+        "Division",
+        "WeightClassKg",
+        "Equipment",
+        "Event",
+        "TotalKg",
+        "Place",
+    ];
+    assert_eq!(check_completeness(&headers), (1, 2));
+
+    // An empty header row is missing everything.
+    assert_eq!(check_completeness(&[]), (7, 4));
+}
+
+#[test]
+fn test_exempt_missing_event() {
+    let exempt = "[divisions]\n[weightclasses]\n\
+                  [exemptions]\n\"[inline_parent]\" = [\"ExemptMissingEvent\"]\n";
+    let not_exempt = "[divisions]\n[weightclasses]\n[exemptions]\n";
+
+    // Exempted entries are counted as info, since the exemption was deliberate.
+    let counted = |data: &str| {
+        let report = check_with_config_report(data, exempt);
+        assert!(!report.count_messages().any());
+        report
+            .messages
+            .iter()
+            .any(|m| matches!(m, Message::Info(s) if s.contains("(ExemptMissingEvent)")))
+    };
+
+    // An empty Event column is allowed only for exempted meets.
+    let data = "Name,WeightClassKg,Sex,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,500,Raw,,1";
+    assert!(counted(data));
+    assert_eq!(check_with_config(data, not_exempt).0, 1);
+
+    // A missing Event column is likewise allowed only for exempted meets.
+    let data = "Name,WeightClassKg,Sex,TotalKg,Equipment,Place\n\
+                Test User,90,M,500,Raw,1";
+    assert!(counted(data));
+    assert_eq!(check_with_config(data, not_exempt).0, 1);
+}
+
+#[test]
+fn test_exempt_bodyweight_zero() {
+    let exempt = "[divisions]\n[weightclasses]\n\
+                  [exemptions]\n\"[inline_parent]\" = [\"ExemptBodyweightZero\"]\n";
+    let not_exempt = "[divisions]\n[weightclasses]\n[exemptions]\n";
+    let infos = |report: &Report| {
+        report
+            .messages
+            .iter()
+            .filter(|m| matches!(m, Message::Info(_)))
+            .count()
+    };
+
+    // Without the exemption, a zero bodyweight is an error.
+    let data = "Name,BodyweightKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,0,M,200,200,Raw,B,1\n\
+                Other User,0.0,M,150,150,Raw,B,2";
+    let report = check_with_config_report(data, not_exempt);
+    assert_eq!(report.count_messages().errors(), 2);
+    assert_eq!(infos(&report), 0);
+
+    // With the exemption, zero is treated as missing and counted.
+    let report = check_with_config_report(data, exempt);
+    assert!(!report.count_messages().any());
+    assert_eq!(infos(&report), 1);
+
+    // Empty bodyweights are fine either way, and aren't counted.
+    let data = "Name,BodyweightKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,,M,200,200,Raw,B,1";
+    assert_eq!(check_with_config(data, not_exempt), (0, 0));
+    let report = check_with_config_report(data, exempt);
+    assert!(!report.count_messages().any());
+    assert_eq!(infos(&report), 0);
+}
+
+#[test]
+fn test_squat4_requires_world_record() {
+    let config = |policy: &str| {
+        format!("[divisions]\n[weightclasses]\n[exemptions]\n[metadata]\n{policy}\n")
+    };
+    let errors = |squat4: &str, policy: &str| {
+        let data = format!(
+            "Name,WeightClassKg,Sex,Squat1Kg,Squat2Kg,Squat3Kg,Squat4Kg,Best3SquatKg,\
+             TotalKg,Equipment,Event,Place\n\
+             Test User,90,M,200,210,220,{squat4},220,220,Raw,S,1"
+        );
+        // A lighter fourth attempt is also out of order, which is reported separately.
+        let report = check_with_config_report(&data, &config(policy));
+        let fourth = |m: &&Message| matches!(m, Message::Error(s) if s.contains("fourth attempts"));
+        report.messages.iter().filter(fourth).count()
+    };
+
+    // Without the metadata, and with "Always", fourth attempts are fine.
+    for policy in ["", "allow_fourth_attempts = \"Always\""] {
+        assert_eq!(errors("", policy), 0);
+        assert_eq!(errors("230", policy), 0);
+        assert_eq!(errors("-230", policy), 0);
+    }
+
+    // "Never" allows no fourth attempts, even failed ones.
+    let never = "allow_fourth_attempts = \"Never\"";
+    assert_eq!(errors("", never), 0);
+    assert_eq!(errors("230", never), 1);
+    assert_eq!(errors("-230", never), 1);
+
+    // "WROnly" allows fourth attempts that beat the lifter's best.
+    let wr_only = "allow_fourth_attempts = \"WROnly\"";
+    assert_eq!(errors("", wr_only), 0);
+    assert_eq!(errors("230", wr_only), 0);
+    assert_eq!(errors("-230", wr_only), 0);
+    assert_eq!(errors("220", wr_only), 1);
+    assert_eq!(errors("-215", wr_only), 1);
+}
+
+#[test]
+fn test_place_consistency() {
+    let header = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n";
+    let check = |data: &str| {
+        let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        let mut rdr = csv::ReaderBuilder::new()
+            .quoting(false)
+            .from_reader(data.as_bytes());
+        let entries = do_check(&mut rdr, None, None, None, report)
+            .unwrap()
+            .entries
+            .unwrap();
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_place_consistency(&entries, &mut report);
+        report.count_messages().errors()
+    };
+
+    // Places that follow totals are fine.
+    let data = format!(
+        "{header}A User,90,M,200,200,Raw,B,1\n\
+         B User,90,M,150,150,Raw,B,2\n\
+         C User,90,M,100,100,Raw,B,3"
+    );
+    assert_eq!(check(&data), 0);
+
+    // Places that contradict totals are errors.
+    let data = format!(
+        "{header}A User,90,M,200,200,Raw,B,2\n\
+         B User,90,M,150,150,Raw,B,1\n\
+         C User,90,M,100,100,Raw,B,3"
+    );
+    assert_eq!(check(&data), 2);
+
+    // Tied totals may share a place, skipping the next, or be split by a tiebreaker.
+    let data = format!(
+        "{header}A User,90,M,200,200,Raw,B,1\n\
+         B User,90,M,200,200,Raw,B,1\n\
+         C User,90,M,100,100,Raw,B,3"
+    );
+    assert_eq!(check(&data), 0);
+    let data = format!(
+        "{header}A User,90,M,200,200,Raw,B,2\n\
+         B User,90,M,200,200,Raw,B,1\n\
+         C User,90,M,100,100,Raw,B,3"
+    );
+    assert_eq!(check(&data), 0);
+
+    // The place after a tie must be skipped.
+    let data = format!(
+        "{header}A User,90,M,200,200,Raw,B,1\n\
+         B User,90,M,200,200,Raw,B,1\n\
+         C User,90,M,100,100,Raw,B,2"
+    );
+    assert_eq!(check(&data), 1);
+
+    // DQ entries don't take a place, and other groups are ranked separately.
+    let data = format!(
+        "{header}A User,90,M,,,Raw,B,DQ\n\
+         B User,90,M,150,150,Raw,B,1\n\
+         C User,90,F,100,100,Raw,B,1\n\
+         D User,90,M,100,100,Single-ply,B,1"
+    );
+    assert_eq!(check(&data), 0);
+
+    // Tested and untested lifters are ranked separately, even in the same division.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Tested,Place\n\
+                A User,90,M,200,200,Raw,B,No,1\n\
+                B User,90,M,150,150,Raw,B,Yes,1";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_country_format() {
+    let header = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Country\n";
+    let country = |country: &str| {
+        let data = format!("{header}Test User,90,M,100,100,Raw,B,1,{country}");
+        let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        let mut rdr = csv::ReaderBuilder::new()
+            .quoting(false)
+            .from_reader(data.as_bytes());
+        let report = do_check(&mut rdr, None, None, None, report).unwrap().report;
+        let messages: Vec<String> = report
+            .messages
+            .iter()
+            .map(|m| match m {
+                Message::Error(s) | Message::Warning(s) | Message::Info(s) => s.clone(),
+            })
+            .collect();
+        (report.count_messages().errors(), messages)
+    };
+
+    for valid in ["", "USA", "UK", "Germany", "Yugoslavia"] {
+        assert_eq!(country(valid).0, 0, "Country '{valid}'");
+    }
+
+    // ISO codes suggest the right name.
+    let (errors, messages) = country("US");
+    assert_eq!(errors, 1);
+    assert!(messages[0].ends_with("Unknown Country 'US': use 'USA'"));
+    let (errors, messages) = country("DEU");
+    assert_eq!(errors, 1);
+    assert!(messages[0].ends_with("use 'Germany'"));
+
+    // Garbage has no suggestion.
+    for invalid in ["XX", "usa", "Atlantis"] {
+        let (errors, messages) = country(invalid);
+        assert_eq!(errors, 1, "Country '{invalid}'");
+        assert!(messages[0].ends_with(&format!("Unknown Country '{invalid}'")));
+    }
+
+    // Former countries are fine until they dissolved.
+    let warnings = |country: Country, year: u32| {
+        let entry = Entry {
+            name: "Test User".into(),
+            country: Some(country),
+            entrydate: Date::from_parts(year, 6, 1),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_country_format(&[entry], &mut report);
+        report.count_messages().warnings()
+    };
+    assert_eq!(warnings(Country::Yugoslavia, 1992), 0);
+    assert_eq!(warnings(Country::Yugoslavia, 1993), 1);
+    assert_eq!(warnings(Country::USSR, 2019), 1);
+    assert_eq!(warnings(Country::Russia, 2019), 0);
+}
+
+#[test]
+fn test_place_format() {
+    let header = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n";
+    let place = |place: &str| {
+        let total = if ["DQ", "DD", "NS"].contains(&place) {
+            ""
+        } else {
+            "100"
+        };
+        check(&format!(
+            "{header}Test User,90,M,{total},{total},Raw,B,{place}"
+        ))
+    };
+
+    for valid in ["1", "G", "DQ", "DD", "NS"] {
+        assert_eq!(place(valid), 0, "Place '{valid}'");
+    }
+    for invalid in ["", "0", "-1", "1st", "DNF", "dq", "256"] {
+        assert_ne!(place(invalid), 0, "Place '{invalid}'");
+    }
+
+    // Large places are allowed, but suspicious.
+    let warnings = |place: &str| {
+        let entry = Entry {
+            name: "Test User".into(),
+            place: place.parse::<Place>().unwrap(),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_place_format(&[entry], &mut report);
+        report.count_messages().warnings()
+    };
+    assert_eq!(warnings("100"), 0);
+    assert_eq!(warnings("101"), 1);
+    assert_eq!(warnings("G"), 0);
+}
+
+#[test]
+fn test_event_lift_consistency() {
+    let errors = |event: &str, squat: i32, bench: i32, deadlift: i32| {
+        let entry = Entry {
+            name: "Test User".into(),
+            event: event.parse::<Event>().unwrap(),
+            best3squatkg: WeightKg::from_i32(squat),
+            best3benchkg: WeightKg::from_i32(bench),
+            best3deadliftkg: WeightKg::from_i32(deadlift),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_event_lift_consistency(&[entry], &mut report);
+        report.count_messages().errors()
+    };
+
+    // Each event with only its own lifts, or blank companion lifts.
+    assert_eq!(errors("SBD", 200, 150, 250), 0);
+    assert_eq!(errors("BD", 0, 150, 250), 0);
+    assert_eq!(errors("SB", 200, 150, 0), 0);
+    assert_eq!(errors("S", 200, 0, 0), 0);
+    assert_eq!(errors("B", 0, 150, 0), 0);
+    assert_eq!(errors("D", 0, 0, 250), 0);
+
+    // Each event with non-zero lifts outside of it.
+    assert_eq!(errors("BD", 200, 150, 250), 1);
+    assert_eq!(errors("SB", 200, 150, 250), 1);
+    assert_eq!(errors("S", 200, 150, 250), 2);
+    assert_eq!(errors("B", 200, 150, 250), 2);
+    assert_eq!(errors("D", 200, 150, 250), 2);
+
+    // Failed lifts outside of the event count as data too.
+    assert_eq!(errors("B", -200, 150, 0), 1);
+}
+
+#[test]
+fn test_equipment_lift_consistency() {
+    let warnings = |equipment: &str, attempts: [i32; 3], place: &str, bodyweight: i32| {
+        let entry = Entry {
+            name: "Test User".into(),
+            equipment: equipment.parse().unwrap(),
+            place: place.parse::<Place>().unwrap(),
+            bodyweightkg: WeightKg::from_i32(bodyweight),
+            squat1kg: WeightKg::from_i32(attempts[0]),
+            bench2kg: WeightKg::from_i32(attempts[1]),
+            deadlift3kg: WeightKg::from_i32(attempts[2]),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_equipment_lift_consistency(&[entry], &mut report);
+        report.count_messages().warnings()
+    };
+
+    // Raw lifters aren't checked, even with nothing recorded.
+    assert_eq!(warnings("Raw", [0, 0, 0], "DQ", 90), 0);
+
+    // Equipped lifters with nothing recorded.
+    assert_eq!(warnings("Wraps", [0, 0, 0], "DQ", 90), 1);
+    assert_eq!(warnings("Single-ply", [0, 0, 0], "DQ", 90), 1);
+
+    // Any attempt counts, including failed ones.
+    assert_eq!(warnings("Wraps", [200, 0, 0], "1", 90), 0);
+    assert_eq!(warnings("Single-ply", [0, 0, 250], "1", 90), 0);
+    assert_eq!(warnings("Single-ply", [-200, -150, 0], "DQ", 90), 0);
+
+    // No-shows and entries without a bodyweight are skipped.
+    assert_eq!(warnings("Single-ply", [0, 0, 0], "NS", 90), 0);
+    assert_eq!(warnings("Wraps", [0, 0, 0], "DQ", 0), 0);
+}
+
+#[test]
+fn test_wilks_score_plausibility() {
+    let warnings = |sex: &str, bodyweight: &str, total: &str| {
+        let entry = Entry {
+            name: "Test User".into(),
+            sex: sex.parse().unwrap(),
+            bodyweightkg: bodyweight.parse().unwrap(),
+            totalkg: total.parse().unwrap(),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_wilks_score_plausibility(&[entry], &mut report);
+        report.count_messages().warnings()
+    };
+
+    // Ordinary totals.
+    assert_eq!(warnings("M", "100", "700"), 0);
+    assert_eq!(warnings("F", "60", "400"), 0);
+
+    // At 100kg, a man's Wilks coefficient is about 0.6086.
+    assert_eq!(warnings("M", "100", "1150"), 0); // 699.88
+    assert_eq!(warnings("M", "100", "1151"), 1); // 700.49
+    assert_eq!(warnings("M", "100", "33"), 0); // 20.08
+    assert_eq!(warnings("M", "100", "32.5"), 1); // 19.78
+
+    // A BodyweightKg typo, like 10 for 100.
+    assert_eq!(warnings("M", "10", "700"), 1);
+
+    // Blank totals and bodyweights are skipped, as are disqualified totals.
+    assert_eq!(warnings("M", "100", ""), 0);
+    assert_eq!(warnings("M", "", "700"), 0);
+    assert_eq!(warnings("M", "100", "-700"), 0);
+}
+
+#[test]
+fn test_birth_date_cutoffs() {
+    let config_toml = "[divisions]\n\
+                       u18 = { name = \"U18\", born_after = \"2000-12-31\" }\n\
+                       [weightclasses]\n[exemptions]\n";
+    let configpath = std::env::temp_dir().join(format!("checker_{}_born.toml", std::process::id()));
+    std::fs::write(&configpath, config_toml).unwrap();
+    let config = check_config(configpath.clone(), false)
+        .unwrap()
+        .config
+        .unwrap();
+    std::fs::remove_file(configpath).unwrap();
+
+    let meet = Meet {
+        path: "test/1901".to_string(),
+        federation: Federation::USAPL,
+        date: Date::from_parts(2019, 3, 1),
+        country: Country::USA,
+        state: None,
+        town: None,
+        name: "Test Meet".to_string(),
+        ruleset: RuleSet::default(),
+    };
+    let errors = |birthdate: &str| {
+        let csv = format!(
+            "Name,Sex,Event,Equipment,Division,BirthDate,WeightClassKg,Best3BenchKg,TotalKg,Place\n\
+             Test User,M,B,Raw,U18,{birthdate},90,100,100,1"
+        );
+        let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        let mut rdr = csv::ReaderBuilder::new()
+            .quoting(false)
+            .from_reader(csv.as_bytes());
+        let report = do_check(&mut rdr, Some(&meet), Some(&config), None, report)
+            .unwrap()
+            .report;
+        report
+            .messages
+            .iter()
+            .filter(|m| matches!(m, Message::Error(s) if s.contains("BirthDate")))
+            .count()
+    };
+
+    // The cutoff is exclusive.
+    assert_eq!(errors("2001-01-01"), 0);
+    assert_eq!(errors("2000-12-31"), 1);
+
+    // Lifters without a BirthDate can't be checked.
+    assert_eq!(errors(""), 0);
+}
+
+#[test]
+fn test_total_kg_above_opener() {
+    let errors = |event: &str, squat: &str, bench: &str, deadlift: &str, total: &str| {
+        let entry = Entry {
+            name: "Test User".into(),
+            event: event.parse().unwrap(),
+            best3squatkg: squat.parse().unwrap(),
+            best3benchkg: bench.parse().unwrap(),
+            best3deadliftkg: deadlift.parse().unwrap(),
+            totalkg: total.parse().unwrap(),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_total_kg_above_opener(&[entry], &mut report);
+        report.count_messages().errors()
+    };
+
+    // Full power, including a bombed-out bench that doesn't count.
+    assert_eq!(errors("SBD", "200", "150", "250", "600"), 0);
+    assert_eq!(errors("SBD", "200", "-150", "250", "450"), 0);
+
+    // Single lifts, where the total is the lift.
+    assert_eq!(errors("B", "", "150", "", "150"), 0);
+    assert_eq!(errors("D", "", "", "250", "250"), 0);
+
+    // Lifts outside the event are ignored.
+    assert_eq!(errors("D", "300", "", "250", "250"), 0);
+    assert_eq!(errors("BD", "300", "150", "250", "400"), 0);
+
+    // Totals smaller than a best lift.
+    assert_eq!(errors("SBD", "200", "150", "250", "240"), 1);
+    assert_eq!(errors("SBD", "200", "150", "250", "180"), 2);
+    assert_eq!(errors("B", "", "150", "", "140"), 1);
+
+    // Entries without a total are skipped.
+    assert_eq!(errors("SBD", "200", "150", "250", ""), 0);
+}
+
+#[test]
+fn test_goodlift_points_plausibility() {
+    let warnings = |sex: &str, equipment: &str, event: &str, bodyweight: &str, total: &str| {
+        let entry = Entry {
+            name: "Test User".into(),
+            sex: sex.parse().unwrap(),
+            equipment: equipment.parse().unwrap(),
+            event: event.parse().unwrap(),
+            bodyweightkg: bodyweight.parse().unwrap(),
+            totalkg: total.parse().unwrap(),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_goodlift_points_plausibility(&[entry], &mut report);
+        report.count_messages().warnings()
+    };
+
+    // Ordinary totals.
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "700"), 0); // 88.43
+    assert_eq!(warnings("F", "Raw", "SBD", "60", "400"), 0); // 90.42
+    assert_eq!(warnings("F", "Raw", "B", "60", "100"), 0); // 85.91
+
+    // The bounds, for a Raw man at 100kg.
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "1029"), 0); // 129.99
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "1030"), 1); // 130.12
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "80"), 0); // 10.11
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "79"), 1); // 9.98
+
+    // A total that's plausible equipped, but not Raw.
+    assert_eq!(warnings("M", "Single-ply", "SBD", "100", "1150"), 0); // 120.26
+    assert_eq!(warnings("M", "Raw", "SBD", "100", "1150"), 1);
+
+    // Mx lifters use the men's coefficients, so a woman's record total is fine.
+    assert_eq!(warnings("Mx", "Raw", "SBD", "100", "1029"), 0);
+    assert_eq!(warnings("F", "Raw", "SBD", "100", "1029"), 1);
+
+    // Events and bodyweights the formula doesn't cover are skipped.
+    assert_eq!(warnings("M", "Raw", "D", "100", "5000"), 0);
+    assert_eq!(warnings("M", "Raw", "SBD", "30", "5000"), 0);
+    assert_eq!(warnings("M", "Raw", "SBD", "100", ""), 0);
+    assert_eq!(warnings("M", "Raw", "SBD", "", "700"), 0);
+}
+
+#[test]
+fn test_best_lift_matches_attempts() {
+    // Every discipline uses the same rule, so only the bench is used here.
+    let errors = |attempts: &str, best: &str, place: &str| {
+        // DQ entries have no TotalKg.
+        let total = if place == "DQ" { "" } else { best };
+        let data = format!(
+            "Name,Sex,Event,Equipment,WeightClassKg,Bench1Kg,Bench2Kg,Bench3Kg,\
+             Best3BenchKg,TotalKg,Place\n\
+             Test User,M,B,Raw,90,{attempts},{best},{total},{place}"
+        );
+        check(&data)
+    };
+
+    // All good: the heaviest attempt is the best.
+    assert_eq!(errors("140,145,150", "150", "1"), 0);
+    assert_eq!(errors("140,145,150", "145", "1"), 1);
+
+    // One good, two failed: failures don't count, even if heavier.
+    assert_eq!(errors("140,-150,-150", "140", "1"), 0);
+    assert_eq!(errors("140,-150,-150", "150", "1"), 1);
+
+    // All failed: the best is zero, or for a DQ, the lightest failure.
+    assert_eq!(errors("-140,-140,-145", "", "DQ"), 0);
+    assert_eq!(errors("-140,-140,-145", "-140", "DQ"), 0);
+    assert_eq!(errors("-140,-140,-145", "-145", "DQ"), 1);
+
+    // Partially-recorded attempts compare against what's there.
+    assert_eq!(errors("140,,", "140", "1"), 0);
+    assert_eq!(errors(",,150", "150", "1"), 0);
+    assert_eq!(errors(",,150", "140", "1"), 1);
+}
+
+#[test]
+fn test_division_assignment_consistency() {
+    let entry = |name: &str, division: &str, equipment: &str| Entry {
+        name: name.into(),
+        division: division.into(),
+        equipment: equipment.parse().unwrap(),
+        ..Entry::default()
+    };
+    let warnings = |entries: &[Entry]| {
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_division_assignment_consistency(entries, &mut report);
+        report.count_messages().warnings()
+    };
+
+    // The same lifter twice in the same division is fine.
+    let same = [
+        entry("Test User", "Open", "Multi-ply"),
+        entry("Test User", "Open", "Multi-ply"),
+    ];
+    assert_eq!(warnings(&same), 0);
+
+    // The same lifter twice in different divisions warns once.
+    let different = [
+        entry("Test User", "Open", "Multi-ply"),
+        entry("Other User", "Open", "Multi-ply"),
+        entry("Test User", "Juniors", "Multi-ply"),
+        entry("Test User", "Masters", "Multi-ply"),
+    ];
+    assert_eq!(warnings(&different), 1);
+
+    // Rows with other equipment are separate entries.
+    let equipment = [
+        entry("Test User", "Open", "Multi-ply"),
+        entry("Test User", "Juniors", "Raw"),
+    ];
+    assert_eq!(warnings(&equipment), 0);
+}
+
+#[test]
+fn test_entries_have_required_fields() {
+    let entry = |name: &str, event: &str, total: i32, place: &str| Entry {
+        name: name.into(),
+        event: event.parse::<Event>().unwrap_or_default(),
+        totalkg: WeightKg::from_i32(total),
+        place: place.parse::<Place>().unwrap(),
+        ..Entry::default()
+    };
+    let messages = |entries: &[Entry]| {
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_entries_have_required_fields(entries, &mut report);
+        report
+            .messages
+            .iter()
+            .map(|m| match m {
+                Message::Error(s) => s.clone(),
+                m => panic!("unexpected {m:?}"),
+            })
+            .collect::<Vec<String>>()
+    };
+
+    // Complete rows are fine, and DQ or NS entries don't need a TotalKg.
+    let complete = [
+        entry("A User", "SBD", 500, "1"),
+        entry("B User", "B", 0, "DQ"),
+        entry("C User", "SBD", 0, "NS"),
+    ];
+    assert!(messages(&complete).is_empty());
+
+    // Each missing field is reported with its row.
+    let entries = [
+        entry("A User", "SBD", 500, "1"),
+        entry("", "SBD", 500, "2"),
+        entry("C User", "", 500, "3"),
+        entry("", "B", 0, "4"),
+    ];
+    assert_eq!(
+        messages(&entries),
+        vec![
+            "Row 2 is missing a value for 'Name'",
+            "Row 4 is missing a value for 'Name'",
+            "Row 3 is missing a value for 'Event'",
+            "Row 4 is missing a value for 'TotalKg'",
+        ]
+    );
+
+    // Beyond the first 10 rows, violations are summarized.
+    let entries: Vec<Entry> = (0..25).map(|_| entry("", "SBD", 500, "1")).collect();
+    let messages = messages(&entries);
+    assert_eq!(messages.len(), 11);
+    assert_eq!(messages[9], "Row 10 is missing a value for 'Name'");
+    assert_eq!(messages[10], "15 more rows are missing a value for 'Name'");
+}
+
+#[test]
+fn test_attempt_weight_increments() {
+    let errors = |attempts: [f32; 3]| {
+        let entry = Entry {
+            name: "Test User".into(),
+            bench1kg: WeightKg::from_f32(attempts[0]),
+            bench2kg: WeightKg::from_f32(attempts[1]),
+            bench3kg: WeightKg::from_f32(attempts[2]),
+            ..Entry::default()
+        };
+        let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+        check_consistent_attempt_weight_increments(&[entry], &mut report);
+        report.count_messages().errors()
+    };
+
+    // Valid progressions, including the smallest allowed increment.
+    assert_eq!(errors([100.0, 105.0, 110.0]), 0);
+    assert_eq!(errors([100.0, 102.5, 103.0]), 0);
+    assert_eq!(errors([100.0, 0.0, 110.0]), 0);
+
+    // Failed attempts may be repeated at the same weight, or raised.
+    assert_eq!(errors([100.0, -105.0, 105.0]), 0);
+    assert_eq!(errors([-100.0, -100.0, -100.0]), 0);
+    assert_eq!(errors([-100.0, 100.25, 105.0]), 0);
+
+    // Successful attempts must be raised by at least 0.5kg.
+    assert_eq!(errors([100.0, 100.0, 105.0]), 1);
+    assert_eq!(errors([100.0, 100.25, 105.0]), 1);
+
+    // Weights may not decrease, whether the attempt before succeeded or failed.
+    assert_eq!(errors([100.0, 95.0, 105.0]), 1);
+    assert_eq!(errors([-100.0, 97.5, 102.5]), 1);
+
+    // do_check() only rejects lowered or repeated attempts, not small increments.
+    let row = |bench2: &str| {
+        format!(
+            "Name,WeightClassKg,Sex,Bench1Kg,Bench2Kg,Bench3Kg,Best3BenchKg,\
+             TotalKg,Equipment,Event,Place\n\
+             Test User,90,M,100,{bench2},105,105,105,Raw,B,1"
+        )
+    };
+    assert_eq!(check(&row("100.25")), 0);
+    assert_eq!(check(&row("100")), 1);
+}
@@ -3,8 +3,11 @@
 extern crate checker;
 extern crate csv;
 
-use checker::checklib::meet::do_check;
-use checker::Report;
+use checker::checklib::meet::{
+    check_federation_name_consistency, check_meet_date_format, do_check, Meet,
+};
+use checker::{check_config, Message, Report};
+use opltypes::{Country, Date, Federation, RuleSet};
 
 use std::path::PathBuf;
 
@@ -170,6 +173,62 @@ fn test_date() {
     assert_eq!(check(data), 1);
 }
 
+/// Checks a date's format, returning the Date and the number of errors.
+fn check_format(date: &str) -> (Option<Date>, usize) {
+    let mut report = Report::new(PathBuf::from("[inline]"));
+    let date = check_meet_date_format(date, &mut report);
+    (date, report.count_messages().errors())
+}
+
+#[test]
+fn test_meet_date_format() {
+    // ISO 8601 dates are accepted.
+    assert_eq!(
+        check_format("2020-01-15"),
+        (Some(Date::from_parts(2020, 1, 15)), 0)
+    );
+
+    // US dates are read, but reported.
+    assert_eq!(
+        check_format("01/15/2020"),
+        (Some(Date::from_parts(2020, 1, 15)), 1)
+    );
+
+    // European dates are read, but reported.
+    assert_eq!(
+        check_format("15/01/2020"),
+        (Some(Date::from_parts(2020, 1, 15)), 1)
+    );
+    assert_eq!(
+        check_format("15.01.2020"),
+        (Some(Date::from_parts(2020, 1, 15)), 1)
+    );
+
+    // Dates that could be either are reported for each reading.
+    assert_eq!(check_format("05/04/2020"), (None, 2));
+
+    // Readings that agree are reported once.
+    let mut report = Report::new(PathBuf::from("[inline]"));
+    let date = check_meet_date_format("01/01/2020", &mut report);
+    assert_eq!(date, Some(Date::from_parts(2020, 1, 1)));
+    assert_eq!(report.count_messages().errors(), 1);
+    let Message::Error(message) = &report.messages[0] else {
+        panic!("expected an error");
+    };
+    assert!(message.contains("looks like MM/DD/YYYY or DD/MM/YYYY"));
+
+    // Garbage is rejected.
+    assert_eq!(check_format("garbage"), (None, 1));
+    assert_eq!(check_format("13/13/2020"), (None, 1));
+    assert_eq!(check_format("01/15/20"), (None, 1));
+    assert_eq!(check_format(""), (None, 1));
+
+    // Alternative formats also fail the full date check.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,08/19/2016,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(check(data), 1);
+}
+
 #[test]
 fn test_meetcountry() {
     // MeetCountry is a mandatory column.
@@ -282,3 +341,50 @@ fn test_meetname() {
                 WRPF,2016-08-19,USA,CA,Mountain View,2016 Boss of Bosses 3";
     assert_eq!(check(data), 1);
 }
+
+/// Checks a USAPL meet against a CONFIG.toml naming the given federation,
+/// returning the number of errors.
+fn check_federation_against(config_federation: &str) -> usize {
+    let filename = format!("checker_{}_federation.toml", std::process::id());
+    let configpath = std::env::temp_dir().join(filename);
+    let config_toml = format!(
+        "[options]\nfederation = \"{config_federation}\"\n\
+         [divisions]\n[weightclasses]\n[exemptions]\n"
+    );
+    std::fs::write(&configpath, config_toml).unwrap();
+    let config = check_config(configpath.clone(), false)
+        .unwrap()
+        .config
+        .unwrap();
+    std::fs::remove_file(configpath).unwrap();
+
+    let meet = Meet {
+        path: "usapl/1901".to_string(),
+        federation: Federation::USAPL,
+        date: Date::from_parts(2019, 3, 1),
+        country: Country::USA,
+        state: None,
+        town: None,
+        name: "Test Meet".to_string(),
+        ruleset: RuleSet::default(),
+    };
+
+    let mut report = Report::new(PathBuf::from("[inline]"));
+    check_federation_name_consistency(&config, &meet, &mut report);
+    report.count_messages().errors()
+}
+
+#[test]
+fn test_federation_name_consistency() {
+    // Exact match.
+    assert_eq!(check_federation_against("USAPL"), 0);
+
+    // Case differences are ignored.
+    assert_eq!(check_federation_against("usapl"), 0);
+
+    // Hyphens are ignored.
+    assert_eq!(check_federation_against("USA-PL"), 0);
+
+    // Genuinely different federations are an error.
+    assert_eq!(check_federation_against("USPA"), 1);
+}
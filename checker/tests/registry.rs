@@ -0,0 +1,228 @@
+//! Tests for loading every federation's CONFIG.toml.
+
+extern crate checker;
+
+use checker::ConfigRegistry;
+use opltypes::{Federation, Rule};
+
+use std::fs;
+use std::path::PathBuf;
+
+const VALID_CONFIG: &str = r#"
+[divisions]
+open = { name = "Open", min = 0, max = 999 }
+
+[weightclasses]
+
+[exemptions]
+"#;
+
+/// Creates a meet-data tree with the given (folder, Option<CONFIG.toml>) pairs.
+fn make_meet_data(name: &str, feds: &[(&str, Option<&str>)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("checker_{}_{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (folder, config) in feds {
+        let dir = root.join(folder);
+        fs::create_dir_all(&dir).unwrap();
+        if let Some(config) = config {
+            fs::write(dir.join("CONFIG.toml"), config).unwrap();
+        }
+    }
+    root
+}
+
+#[test]
+fn test_registry() {
+    let root = make_meet_data(
+        "registry",
+        &[
+            ("usapl", Some(VALID_CONFIG)),
+            ("ipf", Some(VALID_CONFIG)),
+            ("wrpf", None),
+            ("uspa", Some("[divisions]\n")),
+            ("apf", Some("not toml = = =")),
+            ("not-a-federation", Some(VALID_CONFIG)),
+        ],
+    );
+    let registry = ConfigRegistry::from_meet_data(&root).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+
+    // Valid configs are available by federation.
+    assert_eq!(registry.len(), 2);
+    assert!(registry.get(Federation::USAPL).is_some());
+    let ipf = registry.get(Federation::IPF).unwrap();
+    assert_eq!(ipf.divisions()[0].name, "Open");
+
+    // Missing and invalid configs are recorded separately.
+    assert!(registry.get(Federation::WRPF).is_none());
+    assert_eq!(registry.missing(), &[Federation::WRPF]);
+
+    let invalid: Vec<Federation> = registry.invalid().iter().map(|(f, _)| *f).collect();
+    assert_eq!(invalid, vec![Federation::APF, Federation::USPA]);
+    for (_, report) in registry.invalid() {
+        assert!(report.count_messages().errors() > 0);
+    }
+}
+
+/// A CONFIG.toml exercising every part of the Config that must survive caching.
+const FULL_CONFIG: &str = r#"
+[options]
+valid_since = "2001-01-01"
+[divisions]
+open = { name = "Raw Open", min = 0, max = 999, equipment = ["Raw", "Wraps"] }
+guest = { name = "Guest", min = 0, max = 999, place = "G" }
+teen = { name = "Teen", min = 13.5, max = 19, sex = "M", sort_order = 1 }
+[aliases]
+"Teens" = "Teen"
+[weightclasses]
+[weightclasses.default_M]
+classes = ["59", "66", "74", "83", "93", "93+"]
+date_range = [["2000-01-01", "2009-12-31"], ["2012-01-01", "2030-12-31"]]
+sex = "M"
+[weightclasses.teen_M]
+classes = ["52", "56.5", "56.5+"]
+date_range = ["2000-01-01", "2030-12-31"]
+sex = "M"
+divisions = ["Teen"]
+[rulesets]
+default = { ruleset = ["CombineRawAndWraps", "FourthAttemptsMayLower"], date_range = ["2000-01-01", "2030-12-31"] }
+[exemptions]
+"1901" = ["ExemptLiftOrder", "ExemptAge"]
+"#;
+
+#[test]
+fn test_registry_cache() {
+    let root = make_meet_data(
+        "registry_cache",
+        &[
+            ("usapl", Some(FULL_CONFIG)),
+            ("ipf", Some(VALID_CONFIG)),
+            ("wrpf", None),
+            ("apf", Some("not toml = = =")),
+        ],
+    );
+    let registry = ConfigRegistry::from_meet_data(&root).unwrap();
+    assert_eq!(registry.len(), 2);
+
+    // Loading the cache gives back the same registry.
+    let cache = root.join("registry.bin");
+    registry.save_cache(&cache).unwrap();
+    let loaded = ConfigRegistry::load_cache(&cache).unwrap();
+    assert_eq!(loaded.len(), registry.len());
+    for federation in [Federation::USAPL, Federation::IPF] {
+        assert_eq!(loaded.get(federation), registry.get(federation));
+    }
+    assert_eq!(loaded.missing(), registry.missing());
+    assert_eq!(loaded.invalid().len(), 1);
+    assert_eq!(loaded.invalid()[0].0, Federation::APF);
+
+    // Lookups work without re-parsing.
+    let usapl = loaded.get(Federation::USAPL).unwrap();
+    assert_eq!(usapl.division_by_name("Teens").unwrap().name, "Teen");
+    assert!(usapl.rulesets[0]
+        .ruleset
+        .contains(Rule::FourthAttemptsMayLower));
+
+    // A cache from another build is rejected. The build stamp comes first,
+    // after its 8-byte length.
+    let mut bytes = fs::read(&cache).unwrap();
+    bytes[8] ^= 0x01;
+    fs::write(&cache, bytes).unwrap();
+    let error = ConfigRegistry::load_cache(&cache).unwrap_err();
+    assert!(error.to_string().contains("Stale registry cache"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+/// Writes a CONFIG.toml with a men's weightclass set for each `(name, from, to, classes)`.
+fn weightclass_config(metadata: &str, sets: &[(&str, &str, &str, &str)]) -> String {
+    let mut toml = format!("[divisions]\n[exemptions]\n[metadata]\n{metadata}\n[weightclasses]\n");
+    for (name, from, to, classes) in sets {
+        toml += &format!(
+            "{name} = {{ classes = [{classes}], date_range = [\"{from}\", \"{to}\"], sex = \"M\" }}\n"
+        );
+    }
+    toml
+}
+
+#[test]
+fn test_registry_parent_weightclasses() {
+    let parent = weightclass_config(
+        "",
+        &[
+            (
+                "old",
+                "1990-01-01",
+                "2010-12-31",
+                r#""75", "82.5", "90", "100", "110", "110+""#,
+            ),
+            (
+                "new",
+                "2011-01-01",
+                "9999-01-01",
+                r#""66", "74", "83", "93", "105", "105+""#,
+            ),
+        ],
+    );
+    let child = weightclass_config(
+        "parent_federation = \"IPF\"",
+        &[
+            // The parent's classes at the time, but ending in its own SHW class.
+            (
+                "old",
+                "1995-01-01",
+                "2010-12-31",
+                r#""75", "82.5", "90", "90+""#,
+            ),
+            // The parent's new classes, but with a class of its own.
+            (
+                "new",
+                "2011-01-01",
+                "9999-01-01",
+                r#""66", "74", "83", "93", "99", "105+""#,
+            ),
+            // A strict subset is fine.
+            ("light", "2011-01-01", "9999-01-01", r#""66", "74", "83""#),
+        ],
+    );
+    let orphan = weightclass_config(
+        "parent_federation = \"APF\"",
+        &[("all", "2000-01-01", "9999-01-01", r#""60", "60+""#)],
+    );
+    let root = make_meet_data(
+        "registry_parent",
+        &[
+            ("ipf", Some(&parent)),
+            ("usapl", Some(&child)),
+            ("wrpf", Some(&orphan)),
+            ("apf", None),
+        ],
+    );
+    let registry = ConfigRegistry::from_meet_data(&root).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+    assert_eq!(registry.len(), 3);
+
+    // Two sets of the child diverge. The parent of the orphan has no
+    // CONFIG.toml, so it isn't checked.
+    let warnings = registry.parent_weightclass_warnings();
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert!(warnings.iter().all(|(f, _)| *f == Federation::USAPL));
+    assert!(warnings
+        .iter()
+        .any(|(_, w)| w.contains("weightclasses.old") && w.contains("[90+]")));
+    assert!(warnings
+        .iter()
+        .any(|(_, w)| w.contains("weightclasses.new") && w.contains("[99]")));
+}
+
+#[test]
+fn test_registry_empty() {
+    let root = make_meet_data("registry_empty", &[]);
+    fs::create_dir_all(&root).unwrap();
+    let registry = ConfigRegistry::from_meet_data(&root).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(registry.is_empty());
+    assert!(registry.missing().is_empty());
+    assert!(registry.invalid().is_empty());
+}
@@ -52,11 +52,36 @@ impl From<Age> for f64 {
 }
 
 impl Age {
+    /// The lowest Age, used as the lower bound of divisions without a minimum age.
+    pub const fn unbounded_min() -> Age {
+        Age::Exact(0)
+    }
+
+    /// The highest Age, used as the upper bound of divisions without a maximum age.
+    ///
+    /// CONFIG.toml files write this as `max = 999` for Open divisions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Age;
+    /// assert_eq!("999".parse::<Age>().unwrap(), Age::unbounded_max());
+    /// assert!(Age::Exact(80).is_definitely_less_than(Age::unbounded_max()));
+    /// ```
+    pub const fn unbounded_max() -> Age {
+        Age::Exact(u8::MAX)
+    }
+
+    /// Whether this is the upper bound of divisions without a maximum age.
+    pub fn is_unbounded_max(self) -> bool {
+        self == Age::unbounded_max()
+    }
+
     /// Convert from an i64. Used by the TOML deserializer.
     pub fn from_i64(n: i64) -> Result<Self, &'static str> {
         // Some of the CONFIG.toml files hardcode 999 to mean "max Age".
         if n == 999 {
-            return Ok(Age::Exact(u8::max_value()));
+            return Ok(Age::unbounded_max());
         }
 
         if n < 0 {
@@ -88,11 +113,31 @@ impl Age {
     /// assert_eq!(Age::from_birthyear_on_date(1988, date), Age::Approximate(30));
     /// ```
     pub fn from_birthyear_on_date(birthyear: u32, on_date: Date) -> Self {
-        let on_year = on_date.year();
+        Age::from_birth_year(birthyear, on_date.year())
+    }
 
-        match on_year.cmp(&birthyear) {
+    /// Given a BirthYear, calculates the approximate age of the lifter
+    /// at a meet in the given year, for when the full birthdate is unknown.
+    ///
+    /// Since the birthday may not have happened yet, the lifter is either
+    /// `meet_year - birth_year - 1` or `meet_year - birth_year`, and the
+    /// Approximate Age stores the lower of the pair. In the birth year itself,
+    /// the lifter can only be 0.
+    ///
+    /// A birth year after the meet year is impossible, so returns `Age::None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Age;
+    /// assert_eq!(Age::from_birth_year(1988, 2019), Age::Approximate(30));
+    /// assert_eq!(Age::from_birth_year(2019, 2019), Age::Approximate(0));
+    /// assert_eq!(Age::from_birth_year(2020, 2019), Age::None);
+    /// ```
+    pub fn from_birth_year(birth_year: u32, meet_year: u32) -> Self {
+        match meet_year.cmp(&birth_year) {
             Ordering::Less => Age::None,
-            Ordering::Greater => Age::Approximate((on_year - birthyear - 1) as u8),
+            Ordering::Greater => Age::Approximate((meet_year - birth_year - 1) as u8),
             Ordering::Equal => Age::Approximate(0),
         }
     }
@@ -219,44 +264,19 @@ impl Age {
     }
 }
 
+/// Orders Ages by value, placing an Approximate Age halfway between its pair,
+/// as in the CSV representation: `Exact(19) < Approximate(19) < Exact(20)`.
+///
+/// Age::None is greater than every other Age.
 impl PartialOrd for Age {
     fn partial_cmp(&self, other: &Age) -> Option<Ordering> {
-        match self {
-            Age::Exact(age) => match other {
-                Age::Exact(other_age) => Some(age.cmp(other_age)),
-                Age::Approximate(other_age) => {
-                    if *other_age == u8::max_value() {
-                        if *age == u8::max_value() {
-                            Some(Ordering::Equal)
-                        } else {
-                            Some(Ordering::Less)
-                        }
-                    } else {
-                        Some(age.cmp(&(other_age + 1)))
-                    }
-                }
-                Age::None => Some(Ordering::Less),
-            },
-            Age::Approximate(age) => match other {
-                Age::Exact(other_age) => {
-                    if *age == u8::max_value() {
-                        if *other_age == u8::max_value() {
-                            Some(Ordering::Equal)
-                        } else {
-                            Some(Ordering::Greater)
-                        }
-                    } else {
-                        Some((age + 1).cmp(other_age))
-                    }
-                }
-                Age::Approximate(other_age) => Some(age.cmp(other_age)),
-                Age::None => Some(Ordering::Less),
-            },
-            Age::None => match other {
-                Age::Exact(_) | Age::Approximate(_) => Some(Ordering::Greater),
-                Age::None => Some(Ordering::Equal),
-            },
-        }
+        // Doubling each age leaves room for the halfway points.
+        let key = |age: &Age| match *age {
+            Age::Exact(age) => (false, u16::from(age) * 2),
+            Age::Approximate(age) => (false, u16::from(age) * 2 + 1),
+            Age::None => (true, 0),
+        };
+        Some(key(self).cmp(&key(other)))
     }
 }
 
@@ -280,7 +300,7 @@ impl FromStr for Age {
 
         // Some of the CONFIG.toml files hardcode 999 to mean "max Age".
         if s == "999" {
-            return Ok(Age::Exact(u8::max_value()));
+            return Ok(Age::unbounded_max());
         }
 
         let v: Vec<&str> = s.split('.').collect();
@@ -364,6 +384,69 @@ mod tests {
         assert_eq!(format!("{a}"), "");
     }
 
+    #[test]
+    fn from_birth_year() {
+        // The birthday may or may not have happened yet.
+        assert_eq!(Age::from_birth_year(1988, 2019), Age::Approximate(30));
+        assert_eq!(Age::from_birth_year(2018, 2019), Age::Approximate(0));
+
+        // In the birth year, the lifter can only be 0.
+        assert_eq!(Age::from_birth_year(2019, 2019), Age::Approximate(0));
+
+        // A birth year after the meet is impossible.
+        assert_eq!(Age::from_birth_year(2020, 2019), Age::None);
+
+        // The Date version agrees, regardless of the day of the year.
+        for date in [
+            Date::from_parts(2019, 01, 01),
+            Date::from_parts(2019, 12, 31),
+        ] {
+            assert_eq!(
+                Age::from_birthyear_on_date(1988, date),
+                Age::Approximate(30)
+            );
+        }
+    }
+
+    #[test]
+    fn unbounded() {
+        let max = Age::unbounded_max();
+        assert_eq!(Age::from_i64(999), Ok(max));
+        assert_eq!("999".parse::<Age>(), Ok(max));
+        assert!(max.is_unbounded_max());
+        assert!(!Age::Exact(99).is_unbounded_max());
+        assert!(!Age::None.is_unbounded_max());
+
+        // The bounds order correctly against real ages.
+        let min = Age::unbounded_min();
+        for age in [Age::Exact(0), Age::Exact(40), Age::Approximate(90)] {
+            assert!(!age.is_definitely_less_than(min));
+            assert!(!age.is_definitely_greater_than(max));
+        }
+        assert!(min.is_definitely_less_than(max));
+    }
+
+    #[test]
+    fn partial_ord() {
+        // Approximate Ages fall between their pair of Exact Ages.
+        assert!(Age::Exact(19) < Age::Approximate(19));
+        assert!(Age::Approximate(19) < Age::Exact(20));
+        assert!(Age::Approximate(19) < Age::Approximate(20));
+        assert!(Age::Exact(40) > Age::Approximate(39));
+
+        // The ordering agrees with equality.
+        assert!(Age::Exact(20) <= Age::Exact(20));
+        assert!(Age::Approximate(19) >= Age::Approximate(19));
+        assert_ne!(
+            Age::Exact(20).partial_cmp(&Age::Approximate(19)),
+            Some(Ordering::Equal)
+        );
+
+        // Age::None sorts last.
+        assert!(Age::None > Age::unbounded_max());
+        assert!(Age::None > Age::Approximate(u8::MAX));
+    }
+
     #[test]
     fn is_definitely_less_than() {
         let approx_17 = Age::Approximate(17); // "17 or 18"
@@ -0,0 +1,14 @@
+//! Defines the CompetitionLevel type.
+
+/// The level of competition that a division is for, like national athletes only.
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumString, PartialEq, Eq, Hash, Serialize)]
+pub enum CompetitionLevel {
+    /// Club or single-gym competition.
+    Local,
+    /// State, provincial, or other sub-national competition.
+    Regional,
+    /// Competition within a single country.
+    National,
+    /// Competition between countries.
+    International,
+}
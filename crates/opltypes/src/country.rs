@@ -291,7 +291,240 @@ pub enum Country {
     Zimbabwe,
 }
 
+/// ISO 3166 codes for each Country, as (Country, alpha-2, alpha-3).
+///
+/// Former countries use their ISO 3166-3 codes. Serbia and Montenegro has no
+/// alpha-2 code here, since its "CS" was previously Czechoslovakia's.
+/// Countries without codes of their own, like England, are omitted.
+const ISO_CODES: &[(Country, Option<&str>, &str)] = &[
+    (Country::Afghanistan, Some("AF"), "AFG"),
+    (Country::Albania, Some("AL"), "ALB"),
+    (Country::Algeria, Some("DZ"), "DZA"),
+    (Country::AmericanSamoa, Some("AS"), "ASM"),
+    (Country::Angola, Some("AO"), "AGO"),
+    (Country::Argentina, Some("AR"), "ARG"),
+    (Country::Armenia, Some("AM"), "ARM"),
+    (Country::Aruba, Some("AW"), "ABW"),
+    (Country::Australia, Some("AU"), "AUS"),
+    (Country::Azerbaijan, Some("AZ"), "AZE"),
+    (Country::Austria, Some("AT"), "AUT"),
+    (Country::Bahamas, Some("BS"), "BHS"),
+    (Country::Bahrain, Some("BH"), "BHR"),
+    (Country::Bangladesh, Some("BD"), "BGD"),
+    (Country::Belarus, Some("BY"), "BLR"),
+    (Country::Belgium, Some("BE"), "BEL"),
+    (Country::Belize, Some("BZ"), "BLZ"),
+    (Country::Benin, Some("BJ"), "BEN"),
+    (Country::Bolivia, Some("BO"), "BOL"),
+    (Country::BosniaAndHerzegovina, Some("BA"), "BIH"),
+    (Country::Botswana, Some("BW"), "BWA"),
+    (Country::Brazil, Some("BR"), "BRA"),
+    (Country::BritishVirginIslands, Some("VG"), "VGB"),
+    (Country::Brunei, Some("BN"), "BRN"),
+    (Country::Bulgaria, Some("BG"), "BGR"),
+    (Country::BurkinaFaso, Some("BF"), "BFA"),
+    (Country::CaboVerde, Some("CV"), "CPV"),
+    (Country::Cambodia, Some("KH"), "KHM"),
+    (Country::Cameroon, Some("CM"), "CMR"),
+    (Country::Canada, Some("CA"), "CAN"),
+    (Country::CaymanIslands, Some("KY"), "CYM"),
+    (Country::CentralAfricanRepublic, Some("CF"), "CAF"),
+    (Country::Chile, Some("CL"), "CHL"),
+    (Country::China, Some("CN"), "CHN"),
+    (Country::Colombia, Some("CO"), "COL"),
+    (Country::Comoros, Some("KM"), "COM"),
+    (Country::Congo, Some("CG"), "COG"),
+    (Country::CookIslands, Some("CK"), "COK"),
+    (Country::CostaRica, Some("CR"), "CRI"),
+    (Country::Croatia, Some("HR"), "HRV"),
+    (Country::Cuba, Some("CU"), "CUB"),
+    (Country::Cyprus, Some("CY"), "CYP"),
+    (Country::Czechia, Some("CZ"), "CZE"),
+    (Country::Czechoslovakia, Some("CS"), "CSK"),
+    (Country::Denmark, Some("DK"), "DNK"),
+    (Country::Djibouti, Some("DJ"), "DJI"),
+    (Country::DominicanRepublic, Some("DO"), "DOM"),
+    (Country::EastGermany, Some("DD"), "DDR"),
+    (Country::EastTimor, Some("TL"), "TLS"),
+    (Country::Ecuador, Some("EC"), "ECU"),
+    (Country::Egypt, Some("EG"), "EGY"),
+    (Country::ElSalvador, Some("SV"), "SLV"),
+    (Country::Estonia, Some("EE"), "EST"),
+    (Country::Eswatini, Some("SZ"), "SWZ"),
+    (Country::Ethiopia, Some("ET"), "ETH"),
+    (Country::Fiji, Some("FJ"), "FJI"),
+    (Country::Finland, Some("FI"), "FIN"),
+    (Country::France, Some("FR"), "FRA"),
+    (Country::Gabon, Some("GA"), "GAB"),
+    (Country::Georgia, Some("GE"), "GEO"),
+    (Country::Germany, Some("DE"), "DEU"),
+    (Country::Ghana, Some("GH"), "GHA"),
+    (Country::Gibraltar, Some("GI"), "GIB"),
+    (Country::Greece, Some("GR"), "GRC"),
+    (Country::Grenada, Some("GD"), "GRD"),
+    (Country::Guatemala, Some("GT"), "GTM"),
+    (Country::Guinea, Some("GN"), "GIN"),
+    (Country::GuineaBissau, Some("GW"), "GNB"),
+    (Country::Guyana, Some("GY"), "GUY"),
+    (Country::Haiti, Some("HT"), "HTI"),
+    (Country::Honduras, Some("HN"), "HND"),
+    (Country::HongKong, Some("HK"), "HKG"),
+    (Country::Hungary, Some("HU"), "HUN"),
+    (Country::Iceland, Some("IS"), "ISL"),
+    (Country::India, Some("IN"), "IND"),
+    (Country::Indonesia, Some("ID"), "IDN"),
+    (Country::Ireland, Some("IE"), "IRL"),
+    (Country::IsleOfMan, Some("IM"), "IMN"),
+    (Country::Israel, Some("IL"), "ISR"),
+    (Country::Italy, Some("IT"), "ITA"),
+    (Country::Iran, Some("IR"), "IRN"),
+    (Country::Iraq, Some("IQ"), "IRQ"),
+    (Country::IvoryCoast, Some("CI"), "CIV"),
+    (Country::Jamaica, Some("JM"), "JAM"),
+    (Country::Japan, Some("JP"), "JPN"),
+    (Country::Jordan, Some("JO"), "JOR"),
+    (Country::Kazakhstan, Some("KZ"), "KAZ"),
+    (Country::Kenya, Some("KE"), "KEN"),
+    (Country::Kiribati, Some("KI"), "KIR"),
+    (Country::Kuwait, Some("KW"), "KWT"),
+    (Country::Kyrgyzstan, Some("KG"), "KGZ"),
+    (Country::Laos, Some("LA"), "LAO"),
+    (Country::Latvia, Some("LV"), "LVA"),
+    (Country::Lebanon, Some("LB"), "LBN"),
+    (Country::Lesotho, Some("LS"), "LSO"),
+    (Country::Liberia, Some("LR"), "LBR"),
+    (Country::Libya, Some("LY"), "LBY"),
+    (Country::Lithuania, Some("LT"), "LTU"),
+    (Country::Luxembourg, Some("LU"), "LUX"),
+    (Country::Malaysia, Some("MY"), "MYS"),
+    (Country::Mali, Some("ML"), "MLI"),
+    (Country::Malta, Some("MT"), "MLT"),
+    (Country::MarshallIslands, Some("MH"), "MHL"),
+    (Country::Mauritania, Some("MR"), "MRT"),
+    (Country::Mauritius, Some("MU"), "MUS"),
+    (Country::Mexico, Some("MX"), "MEX"),
+    (Country::Moldova, Some("MD"), "MDA"),
+    (Country::Monaco, Some("MC"), "MCO"),
+    (Country::Mongolia, Some("MN"), "MNG"),
+    (Country::Montenegro, Some("ME"), "MNE"),
+    (Country::Morocco, Some("MA"), "MAR"),
+    (Country::Myanmar, Some("MM"), "MMR"),
+    (Country::Namibia, Some("NA"), "NAM"),
+    (Country::Nauru, Some("NR"), "NRU"),
+    (Country::Nepal, Some("NP"), "NPL"),
+    (Country::Netherlands, Some("NL"), "NLD"),
+    (Country::NetherlandsAntilles, Some("AN"), "ANT"),
+    (Country::NewCaledonia, Some("NC"), "NCL"),
+    (Country::NewZealand, Some("NZ"), "NZL"),
+    (Country::Nicaragua, Some("NI"), "NIC"),
+    (Country::Niger, Some("NE"), "NER"),
+    (Country::Nigeria, Some("NG"), "NGA"),
+    (Country::Niue, Some("NU"), "NIU"),
+    (Country::Norway, Some("NO"), "NOR"),
+    (Country::NorthMacedonia, Some("MK"), "MKD"),
+    (Country::Oman, Some("OM"), "OMN"),
+    (Country::Pakistan, Some("PK"), "PAK"),
+    (Country::Palestine, Some("PS"), "PSE"),
+    (Country::Panama, Some("PA"), "PAN"),
+    (Country::PapuaNewGuinea, Some("PG"), "PNG"),
+    (Country::Paraguay, Some("PY"), "PRY"),
+    (Country::Peru, Some("PE"), "PER"),
+    (Country::Philippines, Some("PH"), "PHL"),
+    (Country::Poland, Some("PL"), "POL"),
+    (Country::Portugal, Some("PT"), "PRT"),
+    (Country::PuertoRico, Some("PR"), "PRI"),
+    (Country::Qatar, Some("QA"), "QAT"),
+    (Country::Rhodesia, Some("RH"), "RHO"),
+    (Country::Romania, Some("RO"), "ROU"),
+    (Country::Russia, Some("RU"), "RUS"),
+    (Country::Rwanda, Some("RW"), "RWA"),
+    (Country::Samoa, Some("WS"), "WSM"),
+    (Country::SaudiArabia, Some("SA"), "SAU"),
+    (Country::Senegal, Some("SN"), "SEN"),
+    (Country::Serbia, Some("RS"), "SRB"),
+    (Country::SerbiaAndMontenegro, None, "SCG"),
+    (Country::SierraLeone, Some("SL"), "SLE"),
+    (Country::Singapore, Some("SG"), "SGP"),
+    (Country::Slovakia, Some("SK"), "SVK"),
+    (Country::Slovenia, Some("SI"), "SVN"),
+    (Country::SolomonIslands, Some("SB"), "SLB"),
+    (Country::SouthAfrica, Some("ZA"), "ZAF"),
+    (Country::SouthKorea, Some("KR"), "KOR"),
+    (Country::Spain, Some("ES"), "ESP"),
+    (Country::SriLanka, Some("LK"), "LKA"),
+    (Country::Sudan, Some("SD"), "SDN"),
+    (Country::Suriname, Some("SR"), "SUR"),
+    (Country::Sweden, Some("SE"), "SWE"),
+    (Country::Syria, Some("SY"), "SYR"),
+    (Country::Switzerland, Some("CH"), "CHE"),
+    (Country::Tahiti, Some("PF"), "PYF"),
+    (Country::Taiwan, Some("TW"), "TWN"),
+    (Country::Tajikistan, Some("TJ"), "TJK"),
+    (Country::Tanzania, Some("TZ"), "TZA"),
+    (Country::Thailand, Some("TH"), "THA"),
+    (Country::TheGambia, Some("GM"), "GMB"),
+    (Country::Togo, Some("TG"), "TGO"),
+    (Country::Tonga, Some("TO"), "TON"),
+    (Country::TrinidadAndTobago, Some("TT"), "TTO"),
+    (Country::Tunisia, Some("TN"), "TUN"),
+    (Country::Turkey, Some("TR"), "TUR"),
+    (Country::Turkmenistan, Some("TM"), "TKM"),
+    (Country::Tuvalu, Some("TV"), "TUV"),
+    (Country::UAE, Some("AE"), "ARE"),
+    (Country::Uganda, Some("UG"), "UGA"),
+    (Country::UK, Some("GB"), "GBR"),
+    (Country::Ukraine, Some("UA"), "UKR"),
+    (Country::Uruguay, Some("UY"), "URY"),
+    (Country::USA, Some("US"), "USA"),
+    (Country::USSR, Some("SU"), "SUN"),
+    (Country::USVirginIslands, Some("VI"), "VIR"),
+    (Country::Uzbekistan, Some("UZ"), "UZB"),
+    (Country::Vanuatu, Some("VU"), "VUT"),
+    (Country::Venezuela, Some("VE"), "VEN"),
+    (Country::Vietnam, Some("VN"), "VNM"),
+    (Country::WallisAndFutuna, Some("WF"), "WLF"),
+    (Country::Yemen, Some("YE"), "YEM"),
+    (Country::Yugoslavia, Some("YU"), "YUG"),
+    (Country::Zambia, Some("ZM"), "ZMB"),
+    (Country::Zimbabwe, Some("ZW"), "ZWE"),
+];
+
 impl Country {
+    /// Looks up a Country by its ISO 3166 alpha-2 or alpha-3 code, like "US" or "DEU".
+    ///
+    /// Codes aren't valid in the data, but are a common mistake when transcribing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Country;
+    /// assert_eq!(Country::from_iso_code("US"), Some(Country::USA));
+    /// assert_eq!(Country::from_iso_code("GBR"), Some(Country::UK));
+    /// assert_eq!(Country::from_iso_code("XX"), None);
+    /// ```
+    pub fn from_iso_code(code: &str) -> Option<Country> {
+        ISO_CODES
+            .iter()
+            .find(|&&(_, alpha2, alpha3)| alpha2 == Some(code) || alpha3 == code)
+            .map(|&(country, _, _)| country)
+    }
+
+    /// The last year in which a former country existed, or `None` if it still exists.
+    #[inline]
+    pub fn dissolved_year(self) -> Option<u32> {
+        match self {
+            Country::Czechoslovakia => Some(1993),
+            Country::EastGermany => Some(1990),
+            Country::NetherlandsAntilles => Some(2010),
+            Country::Rhodesia => Some(1979),
+            Country::SerbiaAndMontenegro => Some(2006),
+            Country::USSR => Some(1991),
+            Country::WestGermany => Some(1990),
+            Country::Yugoslavia => Some(1992),
+            _ => None,
+        }
+    }
+
     /// Whether this Country contains the other. Countries contain themselves.
     #[inline]
     pub fn contains(self, other: Country) -> bool {
@@ -256,6 +256,69 @@ impl Date {
     }
 }
 
+impl Date {
+    /// Counts the number of days since the Unix epoch (`1970-01-01`).
+    ///
+    /// Dates before the epoch produce negative counts. The calculation uses
+    /// the proleptic Gregorian calendar, so leap years follow the modern rules
+    /// for all years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Date;
+    /// let epoch = "1970-01-01".parse::<Date>().unwrap();
+    /// assert_eq!(epoch.days_since_epoch(), 0);
+    ///
+    /// let date = "1969-12-31".parse::<Date>().unwrap();
+    /// assert_eq!(date.days_since_epoch(), -1);
+    /// ```
+    pub fn days_since_epoch(self) -> i64 {
+        // Treat March as the first month of the year, so that any leap day
+        // falls at the end of the year.
+        let (month, day) = (i64::from(self.month()), i64::from(self.day()));
+        let year = i64::from(self.year()) - i64::from(month <= 2);
+
+        // The calendar repeats every 400 years ("eras") of 146,097 days.
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        // 719,468 is the number of days from 0000-03-01 to 1970-01-01.
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the Date that is the given number of days after this one.
+    ///
+    /// Negative counts move backwards in time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Date;
+    /// let date = "2020-02-28".parse::<Date>().unwrap();
+    /// assert_eq!(date.add_days(1).to_string(), "2020-02-29");
+    /// assert_eq!(date.add_days(2).to_string(), "2020-03-01");
+    /// assert_eq!(date.add_days(-59).to_string(), "2019-12-31");
+    /// ```
+    pub fn add_days(self, n: i64) -> Date {
+        // Inverse of `days_since_epoch()`, again with years starting in March.
+        let days = self.days_since_epoch() + n + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = (shifted_month + 2) % 12 + 1;
+        let year = era * 400 + year_of_era + i64::from(month <= 2);
+        Date::from_parts(year as u32, month as u32, day as u32)
+    }
+}
+
 impl fmt::Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (y, m, d) = (self.year(), self.month(), self.day());
@@ -266,8 +329,12 @@ impl fmt::Display for Date {
 impl ops::Sub for Date {
     type Output = i32;
 
+    /// Returns the signed number of days between two Dates.
+    ///
+    /// Every Date is within a few million days of another, so this fits in an `i32`.
+    /// For an `i64` count, compare [`Date::days_since_epoch`] values instead.
     fn sub(self, other: Date) -> i32 {
-        self.count_days() as i32 - other.count_days() as i32
+        (self.days_since_epoch() - other.days_since_epoch()) as i32
     }
 }
 
@@ -398,6 +465,29 @@ mod test {
         assert!("2017-03-00".parse::<Date>().is_err());
     }
 
+    #[test]
+    fn is_valid() {
+        let valid = |s: &str| s.parse::<Date>().unwrap().is_valid();
+
+        // February 29 only exists in leap years, which skip most centuries.
+        assert!(valid("2000-02-29"));
+        assert!(valid("2020-02-29"));
+        assert!(!valid("2019-02-29"));
+        assert!(!valid("1900-02-29"));
+        assert!(!valid("2020-02-30"));
+
+        // Month boundaries.
+        assert!(valid("2019-01-31"));
+        assert!(valid("2019-04-30"));
+        assert!(!valid("2019-04-31"));
+        assert!(valid("2019-12-31"));
+
+        // Dates around the Unix epoch.
+        assert!(valid("1969-12-31"));
+        assert!(valid("1970-01-01"));
+        assert!(!valid("1970-02-29"));
+    }
+
     #[test]
     fn ordering() {
         let d1 = "2017-01-12".parse::<Date>().unwrap();
@@ -498,4 +588,56 @@ mod test {
         assert_eq!(on_leap_day - before_leap_day, 1);
         assert_eq!(after_leap_day - before_leap_day, 2);
     }
+
+    #[test]
+    fn days_since_epoch() {
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        assert_eq!(date("1970-01-01").days_since_epoch(), 0);
+        assert_eq!(date("1970-12-31").days_since_epoch(), 364);
+        assert_eq!(date("2000-01-01").days_since_epoch(), 10_957);
+        assert_eq!(date("1900-01-01").days_since_epoch(), -25_567);
+
+        // Agrees with the Common Era day count.
+        for s in ["0001-01-01", "1600-02-29", "1982-06-11", "2024-12-31"] {
+            let d = date(s);
+            assert_eq!(d.days_since_epoch(), i64::from(d.count_days()) - 719_163);
+        }
+    }
+
+    #[test]
+    fn subtraction() {
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        // Leap years have a February 29th.
+        assert_eq!(date("2024-03-01") - date("2024-02-28"), 2);
+        assert_eq!(date("2023-03-01") - date("2023-02-28"), 1);
+
+        // Century years are only leap years if divisible by 400.
+        assert_eq!(date("1900-03-01") - date("1900-02-28"), 1);
+        assert_eq!(date("2000-03-01") - date("2000-02-28"), 2);
+        assert_eq!(date("2001-01-01") - date("2000-01-01"), 366);
+        assert_eq!(date("1901-01-01") - date("1900-01-01"), 365);
+
+        // Differences are signed.
+        assert_eq!(date("2019-04-05") - date("2019-04-06"), -1);
+        assert_eq!(date("1969-12-31") - date("1970-01-01"), -1);
+    }
+
+    #[test]
+    fn add_days() {
+        let date = |s: &str| s.parse::<Date>().unwrap();
+
+        assert_eq!(date("2024-02-28").add_days(1), date("2024-02-29"));
+        assert_eq!(date("1900-02-28").add_days(1), date("1900-03-01"));
+        assert_eq!(date("2000-02-28").add_days(1), date("2000-02-29"));
+        assert_eq!(date("2019-01-01").add_days(-1), date("2018-12-31"));
+        assert_eq!(date("1970-01-01").add_days(-25_567), date("1900-01-01"));
+
+        // Adding days is the inverse of subtraction.
+        let start = date("1899-11-15");
+        for n in (-100_000i32..100_000).step_by(997) {
+            assert_eq!(start.add_days(n.into()) - start, n);
+        }
+    }
 }
@@ -4,7 +4,7 @@ use std::fmt;
 
 /// The Equipment field.
 #[derive(
-    Clone, Copy, Debug, Default, Deserialize, EnumString, PartialEq, Eq, Serialize, PartialOrd,
+    Clone, Copy, Debug, Default, Deserialize, EnumString, PartialEq, Eq, Hash, Serialize, PartialOrd,
 )]
 pub enum Equipment {
     /// No supportive material (sleeves allowed).
@@ -10,7 +10,7 @@ use std::str::FromStr;
 /// The definition of the "Event" column.
 /// An `Event` is represented as a bitfield, with
 /// one bit for each of S, B, and D.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Event(u8);
 
 impl Event {
@@ -33,6 +33,9 @@ pub use self::birthyearclass::BirthYearClass;
 mod birthyearrange;
 pub use self::birthyearrange::BirthYearRange;
 
+mod competitionlevel;
+pub use self::competitionlevel::CompetitionLevel;
+
 mod country;
 pub use self::country::Country;
 
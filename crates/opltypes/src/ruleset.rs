@@ -9,7 +9,9 @@ use std::str::FromStr;
 /// A rule of competition.
 ///
 /// By default, all equipment divisions are assumed to be separate.
-#[derive(Copy, Clone, Debug, Deserialize, Display, EnumString, PartialEq, Eq, Serialize)]
+#[derive(
+    Copy, Clone, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
 pub enum Rule {
     /// Lifters in "Raw" and "Wraps" compete in the same category.
     CombineRawAndWraps,
@@ -127,11 +129,16 @@ impl<'de> Visitor<'de> for RuleSetVisitor {
     fn visit_str<E: de::Error>(self, value: &str) -> Result<RuleSet, E> {
         RuleSet::from_str(value).map_err(E::custom)
     }
+
+    // Non-default RuleSets are serialized as numbers.
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<RuleSet, E> {
+        u32::try_from(value).map(RuleSet).map_err(E::custom)
+    }
 }
 
 impl<'de> Deserialize<'de> for RuleSet {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<RuleSet, D::Error> {
-        deserializer.deserialize_str(RuleSetVisitor)
+        deserializer.deserialize_any(RuleSetVisitor)
     }
 }
 
@@ -171,6 +178,19 @@ mod test {
         assert_eq!(ruleset.contains(Rule::CombineSingleAndMulti), true);
     }
 
+    #[test]
+    fn serde_roundtrip() {
+        for s in [
+            "",
+            "CombineRawAndWraps",
+            "CombineRawAndWraps CombineSingleAndMulti",
+        ] {
+            let ruleset = s.parse::<RuleSet>().unwrap();
+            let json = serde_json::to_string(&ruleset).unwrap();
+            assert_eq!(serde_json::from_str::<RuleSet>(&json).unwrap(), ruleset);
+        }
+    }
+
     #[test]
     fn errors() {
         let s = "CombineFloobAndBleeb";
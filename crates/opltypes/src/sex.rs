@@ -2,7 +2,7 @@
 
 /// The Sex column.
 #[derive(
-    Clone, Copy, Debug, Default, Deserialize, Display, EnumString, PartialEq, Eq, Serialize,
+    Clone, Copy, Debug, Default, Deserialize, Display, EnumString, PartialEq, Eq, Hash, Serialize,
 )]
 pub enum Sex {
     /// Male.
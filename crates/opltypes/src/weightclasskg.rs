@@ -12,7 +12,7 @@ use std::str::FromStr;
 use crate::{WeightAny, WeightKg, WeightUnits};
 
 /// The definition of the "WeightClassKg" column.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum WeightClassKg {
     /// A class defined as being under or equal to a maximum weight.
     UnderOrEqual(WeightKg),
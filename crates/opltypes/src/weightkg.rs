@@ -20,7 +20,7 @@ use crate::WeightUnits;
 /// Instead of storing as `f32`, we can store as `i32 * 100`,
 /// allowing the use of normal registers for what are effectively
 /// floating-point operations, and removing all `dtoa()` calls.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub struct WeightKg(i32);
 
 /// Represents numbers describing absolute weights in their final